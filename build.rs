@@ -13,6 +13,9 @@ pub struct Spec {
 #[derive(Deserialize)]
 pub struct TableSpec {
     columns: Vec<ColumnSpec>,
+    /// The unique ID column, if it is not the first one
+    #[serde(default)]
+    unique_id: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -48,20 +51,31 @@ fn run() -> Result<(), io::Error> {
     let mut cspecs = Vec::with_capacity(spec.tables.len());
     let mut tables = Vec::with_capacity(spec.tables.len());
     let mut rows = Vec::with_capacity(spec.tables.len());
+    let mut column_names = Vec::with_capacity(spec.tables.len());
+    let mut serialize_all = Vec::with_capacity(spec.tables.len());
+    let mut schema_names = Vec::with_capacity(spec.tables.len());
 
     for (name, tspec) in &spec.tables {
         let t = name.to_camel_case();
         let tname = format_ident!("{}Table", t);
         let rname = format_ident!("{}Row", t);
+        let rname_str = rname.to_string();
         let csname = format_ident!("{}Column", t);
 
         let ccount = tspec.columns.len();
         let mut cslist = Vec::with_capacity(ccount);
         let mut exlist = Vec::with_capacity(ccount);
         let mut ser_stmts = Vec::with_capacity(ccount);
+        let mut sel_stmts = Vec::with_capacity(ccount);
         let mut cmatch = Vec::with_capacity(ccount);
-
-        for cspec in &tspec.columns {
+        let mut ctypes = Vec::with_capacity(ccount);
+        let mut cnames = Vec::with_capacity(ccount);
+        let mut cvariants = Vec::with_capacity(ccount);
+        let mut schema_props = Vec::with_capacity(ccount);
+
+        for (cindex, cspec) in tspec.columns.iter().enumerate() {
+            // Some variants of the database use text keys, see `crate::field_key`
+            let is_int_key = cindex == 0 && matches!(cspec.ty, ValueType::Integer);
             let cn = &cspec.name;
             let c = cspec.name.to_snake_case();
             let cf = cspec.name.to_camel_case();
@@ -77,14 +91,55 @@ fn run() -> Result<(), io::Error> {
                 #[doc = #doc]
                 #cfname
             });
+            cnames.push(quote! {
+                Self::#cfname => #cn
+            });
+            cvariants.push(quote!(Self::#cfname));
 
+            // Columns that are missing or have the wrong type are an error, not a panic
+            let value = if cspec.nullable {
+                quote!(self.#cname())
+            } else {
+                let try_name = format_ident!("try_{}", cspec.name.to_snake_case());
+                let msg = format!(
+                    "column `{}` of table `{}` is missing or has the wrong type",
+                    cn, name
+                );
+                quote!(self.#try_name().ok_or_else(|| <S::Error as serde::ser::Error>::custom(#msg))?)
+            };
             ser_stmts.push(quote! {
-                s.serialize_field(#cn, &self.#cname())?;
+                s.serialize_field(#cn, &#value)?;
+            });
+            sel_stmts.push(quote! {
+                if columns.contains(&super::columns::#csname::#cfname) {
+                    s.serialize_field(#cn, &#value)?;
+                }
             });
 
+            // The type of the serialized field, see `Serialize` below
+            let schema_ty = match &cspec.ty {
+                ValueType::Nothing => quote!(()),
+                ValueType::Integer => quote!(i32),
+                ValueType::Float => quote!(f32),
+                ValueType::Text | ValueType::VarChar => quote!(String),
+                ValueType::Boolean => quote!(bool),
+                ValueType::BigInt => quote!(i64),
+            };
+            if cspec.nullable {
+                schema_props.push(quote! {
+                    object.properties.insert(#cn.to_owned(), gen.subschema_for::<Option<#schema_ty>>());
+                });
+            } else {
+                schema_props.push(quote! {
+                    object.properties.insert(#cn.to_owned(), gen.subschema_for::<#schema_ty>());
+                    object.required.insert(#cn.to_owned());
+                });
+            }
+
             let doc = format!("Get the data in column `{}`", &cspec.name);
             let (return_type, map_fn) = match &cspec.ty {
                 ValueType::Nothing => (quote!(()), quote!(|_| Some(()))),
+                ValueType::Integer if is_int_key => (quote!(i32), quote!(crate::field_key)),
                 ValueType::Integer => (quote!(i32), quote!(Field::into_opt_integer)),
                 ValueType::Float => (quote!(f32), quote!(Field::into_opt_float)),
                 ValueType::Text => (quote!(&'a Latin1Str), quote!(Field::into_opt_text)),
@@ -98,7 +153,7 @@ fn run() -> Result<(), io::Error> {
                 quote! {
                     #[doc = #doc]
                     pub fn #cname(&self) -> Option<#return_type> {
-                        let index = self.table.get_col(#columns::#cfname).unwrap();
+                        let index = self.table.get_col(#columns::#cfname)?;
                         self.row.field_at(index).and_then(#map_fn)
                     }
                 }
@@ -109,22 +164,108 @@ fn run() -> Result<(), io::Error> {
                 } else {
                     (quote!(.and_then(#map_fn)), quote!( -> #return_type))
                 };
+                let try_name = format_ident!("try_{}", cspec.name.to_snake_case());
+                let doc = format!(
+                    "{}\n\n# Panics\n\nPanics if the column is missing from the table, or if the field is \
+                     `NULL` or has the wrong type. Use [`Self::{}`] to handle these cases.",
+                    doc, try_name
+                );
+                let try_doc = format!(
+                    "Get the data in column `{}`, or `None` if the column is missing or the field has the wrong type",
+                    &cspec.name
+                );
                 quote! {
                     #[doc = #doc]
                     pub fn #cname(&self) #ret {
                         let index = self.table.get_col(#columns::#cfname).unwrap();
                         self.row.field_at(index)#map.unwrap()
                     }
+
+                    #[doc = #try_doc]
+                    pub fn #try_name(&self) -> Option<#return_type> {
+                        let index = self.table.get_col(#columns::#cfname)?;
+                        self.row.field_at(index)#map
+                    }
                 }
             };
             exlist.push(f);
 
+            let check = match &cspec.ty {
+                ValueType::Nothing => quote!(true),
+                ValueType::Integer if is_int_key => {
+                    quote!(matches!(ty, ValueType::Integer | ValueType::Text))
+                }
+                ValueType::Integer => quote!(matches!(ty, ValueType::Integer)),
+                ValueType::Float => quote!(matches!(ty, ValueType::Float)),
+                ValueType::Text => quote!(matches!(ty, ValueType::Text)),
+                ValueType::Boolean => quote!(matches!(ty, ValueType::Boolean)),
+                ValueType::BigInt => quote!(matches!(ty, ValueType::BigInt)),
+                ValueType::VarChar => quote!(matches!(ty, ValueType::VarChar)),
+            };
+            ctypes.push(quote! {
+                super::columns::#csname::#cfname => #check
+            });
+
             let b = Literal::byte_string(cn.as_bytes());
             cmatch.push(quote! {
                 #b => Some(super::columns::#csname::#cfname)
             });
         }
 
+        let loc_accessor = |col: &str| {
+            let cspec = tspec.columns.iter().find(|cspec| cspec.name == col)?;
+            let name = if cspec.nullable {
+                format_ident!("{}", cspec.name.to_snake_case())
+            } else {
+                format_ident!("try_{}", cspec.name.to_snake_case())
+            };
+            Some(quote!(self.#name()))
+        };
+        let (localize, loc_status) = (loc_accessor("localize"), loc_accessor("locStatus"));
+        let loc_impl = if localize.is_some() || loc_status.is_some() {
+            let localize = localize.unwrap_or_else(|| quote!(None));
+            let loc_status = loc_status.unwrap_or_else(|| quote!(None));
+            quote! {
+                impl<'a, 'b> crate::locale::LocalizationInfo for #rname<'a, 'b> {
+                    fn is_localized(&self) -> Option<bool> {
+                        #localize
+                    }
+
+                    fn localization_status(&self) -> Option<i32> {
+                        #loc_status
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        let unique_id = tspec
+            .unique_id
+            .as_deref()
+            .unwrap_or_else(|| &tspec.columns[0].name);
+        let unique_id = format_ident!("{}", unique_id.to_camel_case());
+
+        serialize_all.push(quote! {
+            if let Some(Ok(inner)) = tables.by_name(#name) {
+                let table = super::tables::#tname::new(inner);
+                for row in table.row_iter() {
+                    rows.push((#name, serde_json::to_value(&row)));
+                }
+            }
+        });
+        schema_names.push(quote! {
+            (#name, {
+                let root = schemars::schema_for!(#rname<'static, 'static>);
+                root.schema
+                    .object
+                    .map_or_else(Vec::new, |object| object.properties.keys().cloned().collect())
+            })
+        });
+        column_names.push(quote! {
+            (#name, #csname::ALL.iter().map(|col| col.name()).collect())
+        });
+
         let doc = format!("Columns in table `{}`\n\nSee also: [`{0}.html>", &name,);
         cspecs.push(quote! {
             #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -132,6 +273,18 @@ fn run() -> Result<(), io::Error> {
             pub enum #csname {
                 #(#cslist),*
             }
+
+            impl #csname {
+                /// All well-known columns of this table
+                pub const ALL: &[Self] = &[#(#cvariants),*];
+
+                /// Get the name of the column in the FDB
+                pub fn name(self) -> &'static str {
+                    match self {
+                        #(#cnames),*
+                    }
+                }
+            }
         });
 
         let doc = format!(
@@ -140,37 +293,63 @@ fn run() -> Result<(), io::Error> {
         );
         tables.push(quote! {
             #[doc = #doc]
-            #[derive(Clone)]
+            #[derive(Copy, Clone)]
             pub struct #tname<'a> {
                 inner: Table<'a>,
-                pub(crate) col: HashMap<super::columns::#csname, usize>,
+                pub(crate) col: [Option<usize>; #ccount],
             }
 
             impl<'a> TypedTable<'a> for #tname<'a> {
                 type Column = super::columns::#csname;
+                const NAME: &'static str = #name;
+
                 fn as_raw(&self) -> Table<'a> {
                     self.inner
                 }
 
+                fn get_col(&self, col: Self::Column) -> Option<usize> {
+                    self.col[col as usize]
+                }
+
+                fn unique_id_column(&self) -> Self::Column {
+                    super::columns::#csname::#unique_id
+                }
+
                 fn new(inner: Table<'a>) -> Self {
-                    let mut col = HashMap::new();
+                    let mut col = [None; #ccount];
                     for (i, c) in inner.column_iter().enumerate() {
                     	let key = match c.name_raw().as_bytes() {
                     		#(#cmatch),*,
                     		_ => None
                     	};
+                    	// Columns with an unexpected type are treated as missing
                     	if let Some(key) = key {
-	                    	col.insert(key, i);
+                    		let ty = c.value_type();
+                    		let valid = match key {
+                    			#(#ctypes),*
+                    		};
+                    		if valid {
+	                    		col[key as usize] = Some(i);
+	                    	}
 						}
                     }
                     Self { inner, col }
                 }
             }
 
+            impl<'a> AsRef<Table<'a>> for #tname<'a> {
+                fn as_ref(&self) -> &Table<'a> {
+                    &self.inner
+                }
+            }
+
             impl<'a> #tname<'a> {
-            	/// Get the "real" index of the well-known column
-                pub fn get_col(&self, col: super::columns::#csname) -> Option<usize> {
-                    self.col.get(&col).copied()
+                /// Try to resolve every well-known column
+                pub fn resolve_columns(&self) -> Vec<(crate::TableName, crate::ColumnName, bool)> {
+                    super::columns::#csname::ALL
+                        .iter()
+                        .map(|&col| (Self::NAME, col.name(), self.get_col(col).is_some()))
+                        .collect()
                 }
 
 				/// Iterate over all rows
@@ -178,14 +357,21 @@ fn run() -> Result<(), io::Error> {
 					crate::RowIter::new(self)
                 }
 
+				/// Iterate over all rows, together with the integer key in the first column
+				///
+				/// Text keys are parsed, see [`crate::field_key`]. Rows where the first column
+				/// is not an integer key are skipped.
+                pub fn entries<'b>(&'b self) -> impl Iterator<Item = (i32, super::rows::#rname<'a, 'b>)> {
+                    self.as_raw().row_iter().filter_map(move |row| {
+                        let key = crate::field_key(row.field_at(0)?)?;
+                        Some((key, <super::rows::#rname as TypedRow<'a, 'b>>::new(row, self)))
+                    })
+                }
+
 				/// Iterate over all rows that have a specific key
                 pub fn key_iter<'b: 'a>(&'b self, key: i32) -> impl Iterator<Item = super::rows::#rname<'a, 'b>> {
-					let hash = key as usize % self.as_raw().bucket_count();
-                    self.as_raw()
-                        .bucket_at(hash)
-                        .unwrap()
-                        .row_iter()
-                        .filter(move |row| row.field_at(0) == Some(Field::Integer(key)))
+                    self.bucket_rows(key)
+                        .filter(move |row| crate::has_key(row, key))
                         .map(move |inner| <super::rows::#rname as TypedRow<'a,'b>>::new(inner, self))
                 }
             }
@@ -215,6 +401,8 @@ fn run() -> Result<(), io::Error> {
                 #(#exlist)*
             }
 
+            #loc_impl
+
             impl<'a, 'b> serde::Serialize for #rname<'a, 'b> {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
                 where
@@ -224,16 +412,54 @@ fn run() -> Result<(), io::Error> {
                     s.end()
                 }
             }
+
+            #[cfg(feature = "schemars")]
+            impl<'a, 'b> schemars::JsonSchema for #rname<'a, 'b> {
+                fn schema_name() -> String {
+                    String::from(#rname_str)
+                }
+
+                fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                    let mut schema = schemars::schema::SchemaObject {
+                        instance_type: Some(schemars::schema::InstanceType::Object.into()),
+                        ..Default::default()
+                    };
+                    let object = schema.object();
+                    #(#schema_props)*
+                    schema.into()
+                }
+            }
+
+            impl<'a, 'b> crate::SerializeColumns for #rname<'a, 'b> {
+                type Column = super::columns::#csname;
+
+                fn serialize_columns<S>(&self, serializer: S, columns: &[Self::Column]) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer {
+                    let len = super::columns::#csname::ALL
+                        .iter()
+                        .filter(|col| columns.contains(col))
+                        .count();
+                    let mut s = serializer.serialize_struct(#name, len)?;
+                    #(#sel_stmts)*
+                    s.end()
+                }
+            }
         });
     }
 
     let columns = quote! {
         #(#cspecs)*
+
+        /// The names of the well-known columns of every table, in order
+        #[cfg(test)]
+        pub(crate) fn all_names() -> Vec<(crate::TableName, Vec<crate::ColumnName>)> {
+            vec![#(#column_names),*]
+        }
     };
 
     let tables = quote! {
-        use assembly_fdb::mem::{Table, Field};
-        use std::collections::HashMap;
+        use assembly_fdb::{common::ValueType, mem::Table};
         use crate::{TypedTable, TypedRow};
 
         #(#tables)*
@@ -242,8 +468,25 @@ fn run() -> Result<(), io::Error> {
     let rows = quote! {
         use assembly_fdb::{common::Latin1Str, mem::{Field, Row}};
         use serde::ser::SerializeStruct;
+        use crate::TypedTable;
 
         #(#rows)*
+
+        /// Serialize every row of every table in `tables` to JSON
+        #[cfg(test)]
+        pub(crate) fn serialize_all(
+            tables: assembly_fdb::mem::Tables<'_>,
+        ) -> Vec<(crate::TableName, serde_json::Result<serde_json::Value>)> {
+            let mut rows = Vec::new();
+            #(#serialize_all)*
+            rows
+        }
+
+        /// The property names in the JSON schema of every row type
+        #[cfg(all(test, feature = "schemars"))]
+        pub(crate) fn schema_names() -> Vec<(crate::TableName, Vec<String>)> {
+            vec![#(#schema_names),*]
+        }
     };
 
     let out_path = Path::new(&out_dir);
@@ -265,6 +508,10 @@ fn run() -> Result<(), io::Error> {
         pub mod tables;
         #[path = #r]
         /// All row types
+        ///
+        /// Rows serialize their fields in the order of the columns in `spec.json`. This order
+        /// is the wire order for formats that are not self-describing, so columns may only be
+        /// appended to a table spec, never reordered.
         pub mod rows;
     };
 
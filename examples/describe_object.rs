@@ -0,0 +1,74 @@
+//! Print everything the typed database knows about an object
+//!
+//! Usage: `cargo run --example describe_object -- <path/to/cdclient.fdb> <LOT>`
+
+use std::{env, fs};
+
+use paradox_typed_db::{ext::ComponentType, util::format_cell, TypedDatabase, TypedTable};
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let path = args
+        .next()
+        .expect("Missing argument <path/to/cdclient.fdb>");
+    let lot: i32 = args
+        .next()
+        .expect("Missing argument <LOT>")
+        .parse()
+        .expect("<LOT> is not an integer");
+
+    let buf = fs::read(&path).expect("Failed to read the FDB file");
+    let db = TypedDatabase::from_bytes(&buf).expect("Failed to load the FDB file");
+
+    let (name, desc) = db
+        .get_object_name_desc(lot)
+        .unwrap_or_else(|| panic!("Object #{} does not exist", lot));
+    println!("{}", name);
+    if !desc.is_empty() {
+        println!("{}", desc);
+    }
+
+    println!("Fields:");
+    for col in db.objects.as_raw().column_iter() {
+        let name = col.name_raw().decode();
+        if let Some(field) = db.get_field("Objects", lot, &name) {
+            println!("- {}: {}", name, format_cell(field));
+        }
+    }
+
+    println!("Components:");
+    for row in db.comp_reg.key_iter(lot) {
+        if let (Some(ty), Some(id)) = (row.try_component_type(), row.try_component_id()) {
+            println!("- {}: {}", ty, id);
+        }
+    }
+
+    let components = db.get_components(lot);
+    if let Some(icon_path) = components.render.and_then(|id| db.get_render_image(id)) {
+        println!("Icon: {}", icon_path.decode());
+    }
+
+    println!("Skills:");
+    for row in db.object_skills.key_iter(lot) {
+        match (row.try_skill_id(), row.cast_on_type()) {
+            (Some(skill_id), Some(cast_on_type)) => {
+                println!("- {} (cast on {})", skill_id, cast_on_type)
+            }
+            (Some(skill_id), None) => println!("- {}", skill_id),
+            (None, _) => {}
+        }
+    }
+
+    let faction = db
+        .comp_reg
+        .key_iter(lot)
+        .find(|row| {
+            row.try_component_type().map(ComponentType::from) == Some(ComponentType::Destructible)
+        })
+        .and_then(|row| row.try_component_id())
+        .and_then(|id| db.destructible_component.key_iter(id).next())
+        .and_then(|row| row.faction());
+    if let Some(faction) = faction {
+        println!("Faction: {}", faction);
+    }
+}
@@ -0,0 +1,78 @@
+//! Feed arbitrary bytes into the database and all lookup methods
+//!
+//! Run with `cargo fuzz run lookups`. The first four bytes are used as the key
+//! for the lookups, the rest is loaded as the FDB file.
+#![no_main]
+
+use std::collections::HashMap;
+
+use libfuzzer_sys::fuzz_target;
+use paradox_typed_db::{
+    columns::{MissionsColumn, ObjectsColumn},
+    TypedDatabase, TypedTable,
+};
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 4 {
+        return;
+    }
+    let (key, buf) = data.split_at(4);
+    let id = i32::from_le_bytes([key[0], key[1], key[2], key[3]]);
+
+    let db = match TypedDatabase::from_bytes(buf) {
+        Ok(db) => db,
+        Err(_) => return,
+    };
+
+    let _ = db.detect_version();
+    let _ = db.resolve_all_columns();
+    let _ = db.get_icon_path(id);
+    let _ = db.get_emote_display(id);
+    let _ = db.get_mission_data(id);
+    let _ = db.get_mission_tasks(id);
+    let _ = db.get_object_name_desc(id);
+    let _ = db.get_render_image(id);
+    let _ = db.get_components(id);
+    let _ = db.get_component_types(id);
+    let _ = db.get_rebuild_rewards(id);
+    let _ = db.get_object_skill_behaviors(id);
+    let _ = db.item_sets.get_data(id);
+    let _ = db.skills.get_data(id);
+    let _ = db.stats();
+    let _ = db.get_object_drops(id);
+    let _ = db.get_mission_prerequisites(id);
+    let _ = db.loot_tables_containing(id);
+    let _ = db.objects_using_icon(id);
+    let _ = db.object_exists(id);
+    let _ = db.get_render_image_for_object(id);
+    let _ = db.get_behavior_template(id);
+    let _ = db.get_behavior_effect(id);
+    let _ = db.behavior_templates_for_effect(id);
+    let _ = db.objects_with_component(id).count();
+    let _ = db.missions_sorted_for_ui("Mission");
+    let _ = db.objects.ref_iter().count();
+    let _ = db.mission_tasks.as_task_icon_iter(id).count();
+
+    let mut locale = HashMap::new();
+    locale.insert(format!("Objects_{}_name", id), String::from("Name"));
+    let _ = db.get_localized_object_name_desc(id, &locale);
+
+    let ids = [id, id.wrapping_add(1), id];
+    let _ = db.get_objects_batch(&ids);
+    let _ = db.get_icon_paths(&ids);
+
+    let _ = db.get_field("Objects", id, "name");
+    let _ = db.get_field("Icons", id, "IconPath");
+    let _ = db.get_field("Missing", id, "id");
+    let _ = db.contains_table("Objects");
+    let _ = db.contains_table("Missing");
+    let _ = db.visit_table("Objects", |table| table.row_iter().count());
+    let _ = db.visit_table("Missing", |table| table.row_iter().count());
+
+    let _ = db.objects.distinct_col_values(ObjectsColumn::Type);
+    let _ = db.missions.distinct_col_values(MissionsColumn::DefinedType);
+    let _ = db.missions.column_min(MissionsColumn::Id);
+    let _ = db.missions.column_max(MissionsColumn::Id);
+    let _ = db.objects.entries().count();
+    let _ = db.mission_tasks.entries().count();
+});
@@ -0,0 +1,70 @@
+//! # Caching wrappers around the database
+
+use assembly_fdb::common::Latin1Str;
+use dashmap::DashMap;
+
+use crate::{ext::Components, TypedDatabase};
+
+/// A [`TypedDatabase`] that memoizes lookups and can be shared between threads
+pub struct ConcurrentCachingDatabase<'db> {
+    inner: TypedDatabase<'db>,
+    components: DashMap<i32, Components>,
+    object_name_desc: DashMap<i32, Option<(String, String)>>,
+    render_image_for_object: DashMap<i32, Option<&'db Latin1Str>>,
+}
+
+impl<'db> ConcurrentCachingDatabase<'db> {
+    /// Wrap a typed database
+    pub fn new(inner: TypedDatabase<'db>) -> Self {
+        Self {
+            inner,
+            components: DashMap::new(),
+            object_name_desc: DashMap::new(),
+            render_image_for_object: DashMap::new(),
+        }
+    }
+
+    /// Get the wrapped database
+    pub fn inner(&self) -> &TypedDatabase<'db> {
+        &self.inner
+    }
+
+    /// Get all components for the specified LOT
+    ///
+    /// See [`TypedDatabase::get_components`]
+    pub fn get_components(&self, id: i32) -> Components {
+        if let Some(cached) = self.components.get(&id) {
+            return *cached;
+        }
+        let components = self.inner.get_components(id);
+        self.components.insert(id, components);
+        components
+    }
+
+    /// Get the name and description for the specified LOT
+    ///
+    /// See [`TypedDatabase::get_object_name_desc`]
+    pub fn get_object_name_desc(&self, id: i32) -> Option<(String, String)> {
+        if let Some(cached) = self.object_name_desc.get(&id) {
+            return cached.clone();
+        }
+        let name_desc = self.inner.get_object_name_desc(id);
+        self.object_name_desc.insert(id, name_desc.clone());
+        name_desc
+    }
+
+    /// Get the image path of the render component of the specified LOT
+    ///
+    /// See [`TypedDatabase::get_render_image_for_object`]
+    pub fn get_render_image_for_object(&self, lot: i32) -> Option<&'db Latin1Str> {
+        if let Some(cached) = self.render_image_for_object.get(&lot) {
+            return *cached;
+        }
+        let render_image = self
+            .get_components(lot)
+            .render
+            .and_then(|id| self.inner.get_render_image(id));
+        self.render_image_for_object.insert(lot, render_image);
+        render_image
+    }
+}
@@ -0,0 +1,142 @@
+//! # Subsets of the database for a single domain
+//!
+//! Tools that only care about one part of the game data can load one of these
+//! instead of a full [`TypedDatabase`], so they only require the tables they use.
+//! This module is only available with the `domains` feature.
+
+use assembly_fdb::mem::Tables;
+
+use crate::{
+    required_table,
+    tables::{
+        BehaviorParameterTable, BehaviorTemplateTable, ComponentsRegistryTable,
+        DestructibleComponentTable, IconsTable, MissionTasksTable, MissionsTable, ObjectsTable,
+        RenderComponentTable, SkillBehaviorTable,
+    },
+    LoadError, TableNameMap, TypedDatabase, TypedTable,
+};
+
+/// The tables for missions and achievements
+#[derive(Clone)]
+pub struct MissionDb<'db> {
+    /// Missions
+    pub missions: MissionsTable<'db>,
+    /// MissionTasks
+    pub mission_tasks: MissionTasksTable<'db>,
+}
+
+impl<'db> MissionDb<'db> {
+    /// Construct a new mission database
+    pub fn new(tables: Tables<'db>) -> Result<Self, LoadError> {
+        Self::new_with_names(tables, &TableNameMap::new())
+    }
+
+    /// Construct a new mission database, where some tables may have been renamed
+    pub fn new_with_names(tables: Tables<'db>, names: &TableNameMap) -> Result<Self, LoadError> {
+        Ok(Self {
+            missions: MissionsTable::new(required_table(&tables, names, "Missions")?),
+            mission_tasks: MissionTasksTable::new(required_table(&tables, names, "MissionTasks")?),
+        })
+    }
+}
+
+/// The tables for skills, behaviors and destructibles
+#[derive(Clone)]
+pub struct CombatDb<'db> {
+    /// BehaviorParameter
+    pub behavior_parameters: BehaviorParameterTable<'db>,
+    /// BehaviorTemplate
+    pub behavior_templates: BehaviorTemplateTable<'db>,
+    /// DestructibleComponent
+    pub destructible_component: DestructibleComponentTable<'db>,
+    /// SkillBehavior
+    pub skills: SkillBehaviorTable<'db>,
+}
+
+impl<'db> CombatDb<'db> {
+    /// Construct a new combat database
+    pub fn new(tables: Tables<'db>) -> Result<Self, LoadError> {
+        Self::new_with_names(tables, &TableNameMap::new())
+    }
+
+    /// Construct a new combat database, where some tables may have been renamed
+    pub fn new_with_names(tables: Tables<'db>, names: &TableNameMap) -> Result<Self, LoadError> {
+        let behavior_parameter_inner = required_table(&tables, names, "BehaviorParameter")?;
+        let behavior_template_inner = required_table(&tables, names, "BehaviorTemplate")?;
+        let destructible_component_inner = required_table(&tables, names, "DestructibleComponent")?;
+        let skill_behavior_inner = required_table(&tables, names, "SkillBehavior")?;
+        Ok(Self {
+            behavior_parameters: BehaviorParameterTable::new(behavior_parameter_inner),
+            behavior_templates: BehaviorTemplateTable::new(behavior_template_inner),
+            destructible_component: DestructibleComponentTable::new(destructible_component_inner),
+            skills: SkillBehaviorTable::new(skill_behavior_inner),
+        })
+    }
+}
+
+/// The tables for objects and their presentation
+#[derive(Clone)]
+pub struct CatalogDb<'db> {
+    /// ComponentsRegistry
+    pub comp_reg: ComponentsRegistryTable<'db>,
+    /// Icons
+    pub icons: IconsTable<'db>,
+    /// Objects
+    pub objects: ObjectsTable<'db>,
+    /// RenderComponent
+    pub render_comp: RenderComponentTable<'db>,
+}
+
+impl<'db> CatalogDb<'db> {
+    /// Construct a new catalog database
+    pub fn new(tables: Tables<'db>) -> Result<Self, LoadError> {
+        Self::new_with_names(tables, &TableNameMap::new())
+    }
+
+    /// Construct a new catalog database, where some tables may have been renamed
+    pub fn new_with_names(tables: Tables<'db>, names: &TableNameMap) -> Result<Self, LoadError> {
+        let components_registry_inner = required_table(&tables, names, "ComponentsRegistry")?;
+        let icons_inner = required_table(&tables, names, "Icons")?;
+        let objects_inner = required_table(&tables, names, "Objects")?;
+        let render_component_inner = required_table(&tables, names, "RenderComponent")?;
+        Ok(Self {
+            comp_reg: ComponentsRegistryTable::new(components_registry_inner),
+            icons: IconsTable::new(icons_inner),
+            objects: ObjectsTable::new(objects_inner),
+            render_comp: RenderComponentTable::new(render_component_inner),
+        })
+    }
+}
+
+/// Views of the full database as the domain subsets
+///
+/// The table handles are [`Copy`], so these are cheap.
+impl<'db> TypedDatabase<'db> {
+    /// Get the mission tables
+    pub fn mission_db(&self) -> MissionDb<'db> {
+        MissionDb {
+            missions: self.missions,
+            mission_tasks: self.mission_tasks,
+        }
+    }
+
+    /// Get the combat tables
+    pub fn combat_db(&self) -> CombatDb<'db> {
+        CombatDb {
+            behavior_parameters: self.behavior_parameters,
+            behavior_templates: self.behavior_templates,
+            destructible_component: self.destructible_component,
+            skills: self.skills,
+        }
+    }
+
+    /// Get the catalog tables
+    pub fn catalog_db(&self) -> CatalogDb<'db> {
+        CatalogDb {
+            comp_reg: self.comp_reg,
+            icons: self.icons,
+            objects: self.objects,
+            render_comp: self.render_comp,
+        }
+    }
+}
@@ -1,23 +1,114 @@
 //! # Extensions to the auto-generated queries
 
-use assembly_fdb::common::{Latin1Str, Value};
+use std::collections::BTreeMap;
+
+use assembly_fdb::common::Latin1Str;
 
 use crate::{
     columns::{ItemSetsColumn, SkillBehaviorColumn},
+    has_key, key_rows,
     tables::{ItemSetsTable, MissionTasksTable, ObjectsTable, SkillBehaviorTable},
-    TypedTable,
+    TableName, TypedTable,
 };
 use serde::Serialize;
 
 /// Well-known components of an object
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Components {
     /// The render component of the object
     pub render: Option<i32>,
 }
 
+impl Components {
+    /// Create a new set of components, where none is present
+    pub fn new() -> Self {
+        Self { render: None }
+    }
+
+    /// Set the render component
+    pub fn with_render(mut self, id: i32) -> Self {
+        self.render = Some(id);
+        self
+    }
+}
+
+/// Data to display an emote
+#[derive(Debug, Clone, Serialize)]
+pub struct EmoteDisplay<'a> {
+    /// The ID of the emote
+    pub id: i32,
+    /// The file name of the icon, if set
+    pub icon_filename: Option<&'a Latin1Str>,
+    /// The chat command, used as the label if there is no localization
+    pub command: Option<&'a Latin1Str>,
+    /// The prefix of the locale keys for this emote (e.g. `Emotes_1`)
+    pub locale_prefix: String,
+}
+
+/// The client version a database was taken from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GateVersion {
+    /// The version string from the `Release_Version` table
+    Release(String),
+    /// No version row, but the schema matches the 1.10.64 client
+    V1_10_64,
+}
+
+/// The visual effect of a behavior
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct BehaviorEffect<'a> {
+    /// The ID of the effect
+    pub effect_id: i32,
+    /// The handle of the effect, if set
+    pub effect_handle: Option<&'a Latin1Str>,
+}
+
+/// An overview of the tables in a database
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DatabaseStats {
+    /// The number of rows in each table, by name
+    pub tables: BTreeMap<TableName, usize>,
+    /// The total number of rows in all tables
+    pub total: usize,
+}
+
+/// A reward for an activity
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityReward {
+    /// The rating needed to earn this reward
+    pub activity_rating: i32,
+    /// The loot matrix the items are drawn from
+    pub loot_matrix_index: Option<i32>,
+    /// The currency table the coins are drawn from
+    pub currency_index: Option<i32>,
+    /// The challenge rating of the reward
+    pub challenge_rating: i32,
+    /// The items in the loot matrix
+    pub drops: Vec<LootDrop>,
+}
+
+/// An item that may drop from an object
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LootDrop {
+    /// The LOT of the item
+    pub item_id: i32,
+    /// The loot table the item is drawn from
+    pub loot_table_index: i32,
+    /// The rarity table used to pick from the loot table
+    pub rarity_table_index: i32,
+    /// The chance that the loot table is used at all
+    pub percent: f32,
+    /// The minimum number of items to drop from the loot table
+    pub min_to_drop: i32,
+    /// The maximum number of items to drop from the loot table
+    pub max_to_drop: i32,
+    /// Whether the item only drops while on a mission
+    pub mission_drop: bool,
+}
+
 /// Data for an item set
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ItemSet {
     /// The object IDs that make up this set
     pub item_ids: Vec<i32>,
@@ -32,42 +123,26 @@ pub struct ItemSet {
 impl<'db> ItemSetsTable<'db> {
     /// Get data for a specific item set
     pub fn get_data(&self, id: i32) -> Option<ItemSet> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let bucket = self.as_raw().bucket_for_hash(hash);
-
-        let col_item_ids = self
-            .get_col(ItemSetsColumn::ItemIDs)
-            .expect("Missing column 'ItemSets::itemIDs'");
-        let col_kit_image = self
-            .get_col(ItemSetsColumn::KitImage)
-            .expect("Missing column 'ItemSets::kitImage'");
-        let col_kit_type = self
-            .get_col(ItemSetsColumn::KitType)
-            .expect("Missing column 'ItemSets::kitType'");
-        let col_kit_rank = self
-            .get_col(ItemSetsColumn::KitRank)
-            .expect("Missing column 'ItemSets::kitRank'");
-
-        for row in bucket.row_iter() {
-            let id_field = row.field_at(0).unwrap();
-
-            if id_field == Value::Integer(id) {
-                let kit_type = row
-                    .field_at(col_kit_type)
-                    .unwrap()
-                    .into_opt_integer()
-                    .unwrap();
+        let rows = key_rows(self.as_raw(), id);
+
+        let col_item_ids = self.get_col(ItemSetsColumn::ItemIDs)?;
+        let col_kit_image = self.get_col(ItemSetsColumn::KitImage)?;
+        let col_kit_type = self.get_col(ItemSetsColumn::KitType)?;
+        let col_kit_rank = self.get_col(ItemSetsColumn::KitRank)?;
+
+        for row in rows {
+            if has_key(&row, id) {
+                let kit_type = row.field_at(col_kit_type)?.into_opt_integer()?;
                 let kit_rank = row
                     .field_at(col_kit_rank)
-                    .unwrap()
-                    .into_opt_integer()
+                    .and_then(|field| field.into_opt_integer())
                     .unwrap_or(0);
-                let kit_image = row.field_at(col_kit_image).unwrap().into_opt_integer();
+                let kit_image = row
+                    .field_at(col_kit_image)
+                    .and_then(|field| field.into_opt_integer());
                 let item_ids = row
-                    .field_at(col_item_ids)
-                    .unwrap()
-                    .into_opt_text()
-                    .unwrap()
+                    .field_at(col_item_ids)?
+                    .into_opt_text()?
                     .decode()
                     .split(',')
                     .map(str::trim)
@@ -86,7 +161,7 @@ impl<'db> ItemSetsTable<'db> {
     }
 }
 
-#[derive(Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
 /// Metadata for a mission
 pub struct Mission {
     /// The icon ID of the mission
@@ -95,7 +170,7 @@ pub struct Mission {
     pub is_mission: bool,
 }
 
-#[derive(Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
 /// Data for a mission task
 pub struct MissionTask {
     /// The icon ID for the task
@@ -104,7 +179,41 @@ pub struct MissionTask {
     pub uid: i32,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// The type of a component in the `ComponentsRegistry` table
+///
+/// Only the types that are used by this crate have their own variant.
+pub enum ComponentType {
+    /// The `RenderComponent` (type 2)
+    Render,
+    /// The `DestructibleComponent` (type 7)
+    Destructible,
+    /// Any other component type
+    Other(i32),
+}
+
+impl ComponentType {
+    /// Get the numeric ID of the component type
+    pub fn id(self) -> i32 {
+        match self {
+            Self::Render => 2,
+            Self::Destructible => 7,
+            Self::Other(id) => id,
+        }
+    }
+}
+
+impl From<i32> for ComponentType {
+    fn from(id: i32) -> Self {
+        match id {
+            2 => Self::Render,
+            7 => Self::Destructible,
+            id => Self::Other(id),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 /// The kind of an entry in the `Missions` table
 pub enum MissionKind {
     /// The entry is an achievement (i.e. is active by default)
@@ -122,17 +231,34 @@ pub struct ObjectRef<'a> {
     pub name: &'a Latin1Str,
 }
 
+#[derive(Debug, Copy, Clone, Serialize)]
+/// An object with the data from multiple tables
+pub struct ResolvedObject<'a> {
+    /// The id of the object
+    pub id: i32,
+    /// The name of the object
+    pub name: Option<&'a Latin1Str>,
+    /// The well-known components of the object
+    pub components: Components,
+    /// The path of the icon asset of the render component
+    pub render_image: Option<&'a Latin1Str>,
+}
+
 impl<'a> ObjectsTable<'a> {
     /// Iterate over all references
+    ///
+    /// Rows without an integer ID or a text name are skipped.
     pub fn ref_iter(&self) -> impl Iterator<Item = ObjectRef<'a>> + '_ {
-        self.row_iter().map(|row| ObjectRef {
-            id: row.id(),
-            name: row.name(),
+        self.row_iter().filter_map(|row| {
+            Some(ObjectRef {
+                id: row.try_id()?,
+                name: row.try_name()?,
+            })
         })
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 /// Data for a skill
 pub struct SkillBehavior {
     /// The icon of the skill
@@ -142,18 +268,15 @@ pub struct SkillBehavior {
 impl<'db> SkillBehaviorTable<'db> {
     /// Get the data for a skill
     pub fn get_data(&self, id: i32) -> Option<SkillBehavior> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let bucket = self.as_raw().bucket_for_hash(hash);
-
-        let col_skill_icon = self
-            .get_col(SkillBehaviorColumn::SkillIcon)
-            .expect("Missing 'SkillBehavior::skillIcon'");
+        let rows = key_rows(self.as_raw(), id);
 
-        for row in bucket.row_iter() {
-            let id_field = row.field_at(0).unwrap();
+        let col_skill_icon = self.get_col(SkillBehaviorColumn::SkillIcon)?;
 
-            if id_field == Value::Integer(id) {
-                let skill_icon = row.field_at(col_skill_icon).unwrap().into_opt_integer();
+        for row in rows {
+            if has_key(&row, id) {
+                let skill_icon = row
+                    .field_at(col_skill_icon)
+                    .and_then(|field| field.into_opt_integer());
 
                 return Some(SkillBehavior { skill_icon });
             }
@@ -162,7 +285,8 @@ impl<'db> SkillBehaviorTable<'db> {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Data for mission tasks
 pub struct MissionTaskIcon {
     /// The uid of the task
@@ -174,10 +298,14 @@ pub struct MissionTaskIcon {
 
 impl<'a> MissionTasksTable<'a> {
     /// Get metadata for all tasks associated with a mission
+    ///
+    /// Rows without an integer `uid` are skipped.
     pub fn as_task_icon_iter(&self, key: i32) -> impl Iterator<Item = MissionTaskIcon> + '_ {
-        self.key_iter(key).map(|x| MissionTaskIcon {
-            uid: x.uid(),
-            large_task_icon_id: x.large_task_icon_id(),
+        self.key_iter(key).filter_map(|x| {
+            Some(MissionTaskIcon {
+                uid: x.try_uid()?,
+                large_task_icon_id: x.large_task_icon_id(),
+            })
         })
     }
 }
@@ -0,0 +1,72 @@
+//! # Loading gzip-compressed FDB files
+
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::GzDecoder;
+
+use crate::{owned::OwnedDatabase, LoadError};
+
+/// The magic bytes at the start of every gzip file
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// An error when loading a gzip-compressed FDB file
+#[derive(Debug)]
+pub enum GzLoadError {
+    /// The file could not be read or decompressed
+    Io(io::Error),
+    /// The file is not gzipped
+    NotGzip(PathBuf),
+    /// The decompressed data is not a valid database
+    Load(LoadError),
+}
+
+impl fmt::Display for GzLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read: {}", e),
+            Self::NotGzip(path) => write!(f, "{} is not a gzip file", path.display()),
+            Self::Load(e) => write!(f, "failed to load: {}", e),
+        }
+    }
+}
+
+impl Error for GzLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::NotGzip(_) => None,
+            Self::Load(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for GzLoadError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<LoadError> for GzLoadError {
+    fn from(e: LoadError) -> Self {
+        Self::Load(e)
+    }
+}
+
+/// Read, decompress and load a gzip-compressed FDB file, like `cdclient.fdb.gz`
+///
+/// The result owns the decompressed bytes, see [`OwnedDatabase`].
+pub fn load_from_gz_path<P: AsRef<Path>>(path: P) -> Result<OwnedDatabase, GzLoadError> {
+    let path = path.as_ref();
+    let compressed = fs::read(path)?;
+    if !compressed.starts_with(&GZIP_MAGIC) {
+        return Err(GzLoadError::NotGzip(path.to_owned()));
+    }
+    let mut buf = Vec::new();
+    GzDecoder::new(&compressed[..]).read_to_end(&mut buf)?;
+    Ok(OwnedDatabase::new(buf)?)
+}
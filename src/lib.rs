@@ -9,27 +9,57 @@
 //! - Enable serialization with the [`serde`](https://serde.rs) crate
 //! - Accept FDBs that may have additional columns and tables
 
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    error::Error,
+    fmt,
+};
+
 use assembly_core::buffer::CastError;
 use assembly_fdb::{
-    common::{Latin1Str, Value},
-    mem::{Row, Table, Tables},
+    common::{Latin1Str, Value, ValueType},
+    mem::{Database, Field, Row, Table, Tables},
 };
 
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
 
+#[cfg(feature = "dashmap")]
+pub mod cache;
+#[cfg(feature = "domains")]
+pub mod domains;
 pub mod ext;
+#[cfg(feature = "flate2")]
+pub mod gz;
+pub mod locale;
+pub mod owned;
+#[cfg(feature = "arc-swap")]
+pub mod reload;
+pub mod util;
 //pub mod typed_rows;
 //pub mod typed_tables;
 
-use columns::{IconsColumn, MissionTasksColumn, MissionsColumn};
+#[cfg(test)]
+mod tests;
+
+use columns::{
+    ComponentsRegistryColumn, EmotesColumn, IconsColumn, MissionTasksColumn, MissionsColumn,
+    ObjectsColumn, RenderComponentColumn,
+};
 use tables::{
-    BehaviorParameterTable, BehaviorTemplateTable, ComponentsRegistryTable,
-    DestructibleComponentTable, IconsTable, ItemSetSkillsTable, ItemSetsTable, LootTableTable,
-    MissionTasksTable, MissionsTable, ObjectSkillsTable, ObjectsTable, RebuildComponentTable,
-    RenderComponentTable, SkillBehaviorTable,
+    ActivityRewardsTable, BehaviorParameterTable, BehaviorTemplateTable, ComponentsRegistryTable,
+    DestructibleComponentTable, EmotesTable, IconsTable, ItemSetSkillsTable, ItemSetsTable,
+    LootMatrixTable, LootTableTable, MissionTasksTable, MissionsTable, ObjectSkillsTable,
+    ObjectsTable, RebuildComponentTable, ReleaseVersionTable, RenderComponentTable,
+    SkillBehaviorTable,
 };
 
-use self::ext::{Components, Mission, MissionTask};
+use rows::{BehaviorTemplateRow, MissionsRow, SkillBehaviorRow};
+
+use self::ext::{
+    ActivityReward, BehaviorEffect, ComponentType, Components, DatabaseStats, EmoteDisplay,
+    GateVersion, LootDrop, Mission, MissionTask, ResolvedObject,
+};
+use self::locale::Localize;
 
 /// ## A "typed" database row
 ///
@@ -40,13 +70,79 @@ use self::ext::{Components, Mission, MissionTask};
 pub trait TypedTable<'de> {
     /// The type representing one well-known column
     type Column: Copy + Clone + Eq;
+    /// The name of the table in the FDB
+    const NAME: TableName;
 
     /// Return the contained "raw" table
     fn as_raw(&self) -> Table<'de>;
+    /// Get the "real" index of the well-known column
+    fn get_col(&self, col: Self::Column) -> Option<usize>;
+    /// Return the well-known unique ID column
+    ///
+    /// This is the first column, unless the spec says otherwise
+    fn unique_id_column(&self) -> Self::Column;
+    /// Return the "real" index of the unique ID column, if present
+    fn unique_id_index(&self) -> Option<usize> {
+        self.get_col(self.unique_id_column())
+    }
     /// Create a typed table from a raw table.
     ///
     /// This function constructs the necessary metadata.
     fn new(inner: Table<'de>) -> Self;
+
+    /// Get the smallest integer value in a column
+    ///
+    /// Fields that are `NULL` or not integers are skipped.
+    fn column_min(&self, col: Self::Column) -> Option<i32> {
+        let index = self.get_col(col)?;
+        self.as_raw()
+            .row_iter()
+            .filter_map(|row| row.field_at(index)?.into_opt_integer())
+            .min()
+    }
+
+    /// Get the largest integer value in a column
+    ///
+    /// Fields that are `NULL` or not integers are skipped.
+    fn column_max(&self, col: Self::Column) -> Option<i32> {
+        let index = self.get_col(col)?;
+        self.as_raw()
+            .row_iter()
+            .filter_map(|row| row.field_at(index)?.into_opt_integer())
+            .max()
+    }
+
+    /// Get the distinct values in a column, in the order they first appear
+    ///
+    /// Values are compared with [`PartialEq`], so text is compared by its bytes.
+    /// Every new value is compared to all values found so far, so this is meant for
+    /// columns with few distinct values, like types or categories.
+    fn distinct_col_values(&self, col: Self::Column) -> Vec<Field<'de>> {
+        let mut values = Vec::new();
+        if let Some(index) = self.get_col(col) {
+            for value in self
+                .as_raw()
+                .row_iter()
+                .filter_map(|row| row.field_at(index))
+            {
+                if !values.contains(&value) {
+                    values.push(value);
+                }
+            }
+        }
+        values
+    }
+
+    /// Get the raw rows that may have `key` in the first column
+    ///
+    /// The FDB hashes integer keys by their value, reinterpreted as a `u32`, so these
+    /// are the rows in the bucket at that value modulo the bucket count. Text keys are
+    /// hashed with sdbm, so for those this is the bucket of the decimal representation
+    /// of `key`. Tables with any other key type are scanned entirely. The rows are not filtered, so they may have any value in the first column.
+    /// This is empty if the table has no buckets.
+    fn bucket_rows(&self, key: i32) -> KeyRows<'de> {
+        key_rows(self.as_raw(), key)
+    }
 }
 
 /// ## A "typed" database row
@@ -71,16 +167,46 @@ where
     where
         Self: Sized,
     {
-        let hash = index_key as usize % table.as_raw().bucket_count();
-        if let Some(b) = table.as_raw().bucket_at(hash) {
-            for r in b.row_iter() {
-                if r.field_at(id_col).and_then(|x| x.into_opt_integer()) == Some(key) {
-                    return Some(Self::new(r, table));
-                }
+        for r in table.bucket_rows(index_key) {
+            if r.field_at(id_col).and_then(field_key) == Some(key) {
+                return Some(Self::new(r, table));
             }
         }
         None
     }
+
+    /// Get a specific entry from the row by unique ID
+    ///
+    /// This is the same as [`TypedRow::get`], but uses the unique ID column of the table.
+    fn get_by_id(table: &'b Self::Table, index_key: i32, key: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let id_col = table.unique_id_index()?;
+        Self::get(table, index_key, key, id_col)
+    }
+
+    /// Get a specific entry from the row by the value of its unique ID column
+    ///
+    /// If the unique ID is the first column, only the bucket of `key` is checked.
+    /// Otherwise, e.g. for `uid` in `MissionTasks`, the unique ID is not indexed and
+    /// this scans the whole table. Use [`TypedRow::get`] if the value of the first
+    /// column is known as well.
+    fn get_by_key(table: &'b Self::Table, key: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let id_col = table.unique_id_index()?;
+        let row = if id_col == 0 {
+            table.bucket_rows(key).find(|row| has_key(row, key))
+        } else {
+            table
+                .as_raw()
+                .row_iter()
+                .find(|row| row.field_at(id_col).and_then(field_key) == Some(key))
+        };
+        row.map(|row| Self::new(row, table))
+    }
 }
 
 /// # Iterator over [`TypedRow`]s
@@ -107,6 +233,90 @@ where
     }
 }
 
+/// Rows that can be serialized with only some of their columns
+///
+/// This is implemented for all generated row types. The fields are emitted in the
+/// usual order, independent of the order in `columns`.
+pub trait SerializeColumns {
+    /// The type representing one well-known column
+    type Column: Copy + Eq;
+
+    /// Serialize the fields for the well-known columns in `columns`
+    fn serialize_columns<S>(
+        &self,
+        serializer: S,
+        columns: &[Self::Column],
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer;
+
+    /// Wrap this row, so that it serializes only the fields in `columns`
+    fn select<'r>(&'r self, columns: &'r [Self::Column]) -> Selected<'r, Self>
+    where
+        Self: Sized,
+    {
+        Selected { row: self, columns }
+    }
+}
+
+/// A row that serializes only some of its columns
+///
+/// See [`SerializeColumns::select`]
+pub struct Selected<'r, R: SerializeColumns> {
+    row: &'r R,
+    columns: &'r [R::Column],
+}
+
+impl<'r, R: SerializeColumns> serde::Serialize for Selected<'r, R> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.row.serialize_columns(serializer, self.columns)
+    }
+}
+
+/// A row that is serialized together with the name of its table
+///
+/// This serializes as a map with an additional `table` entry before the fields of
+/// the row, e.g. `{ "table": "Missions", "id": 1, ... }`.
+///
+/// The fields are flattened into that map, so this only works with self-describing
+/// formats like JSON. Formats that need to know the length of a map up front, like
+/// `bincode`, fail to serialize it; use the row itself with those.
+#[derive(serde::Serialize)]
+pub struct Tagged<'r, R> {
+    table: TableName,
+    #[serde(flatten)]
+    row: &'r R,
+}
+
+impl<'r, R> Tagged<'r, R> {
+    /// Tag a row with the name of its table
+    pub fn new<'a, 'b>(row: &'r R) -> Self
+    where
+        R: TypedRow<'a, 'b>,
+    {
+        Self {
+            table: <R::Table as TypedTable<'a>>::NAME,
+            row,
+        }
+    }
+}
+
+// Not derived, because that would require `R: Clone`
+impl<'a, 'b, R> Clone for RowIter<'a, 'b, R>
+where
+    R: TypedRow<'a, 'b>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            table: self.table,
+        }
+    }
+}
+
 impl<'a, 'b, R> Iterator for RowIter<'a, 'b, R>
 where
     R: TypedRow<'a, 'b>,
@@ -118,23 +328,37 @@ where
     }
 }
 
+/// The name of a table in the FDB
+pub type TableName = &'static str;
+/// The name of a column in the FDB
+pub type ColumnName = &'static str;
+
 #[derive(Clone)]
 /// A selection of relevant database tables
+///
+/// All typed table handles are [`Copy`]: they consist of the raw [`Table`] and a
+/// fixed-size array of column indices, so copying one out of this struct is cheap.
 pub struct TypedDatabase<'db> {
+    /// ActivityRewards (optional)
+    pub activity_rewards: Option<ActivityRewardsTable<'db>>,
     /// BehaviorParameter
     pub behavior_parameters: BehaviorParameterTable<'db>,
     /// BehaviorTemplate
     pub behavior_templates: BehaviorTemplateTable<'db>,
-    /// ComponentRegistry
+    /// ComponentsRegistry
     pub comp_reg: ComponentsRegistryTable<'db>,
     /// DestructibleComponent
     pub destructible_component: DestructibleComponentTable<'db>,
+    /// Emotes (optional)
+    pub emotes: Option<EmotesTable<'db>>,
     /// Icons
     pub icons: IconsTable<'db>,
     /// ItemSets
     pub item_sets: ItemSetsTable<'db>,
     /// ItemSetSkills
     pub item_set_skills: ItemSetSkillsTable<'db>,
+    /// LootMatrix (optional)
+    pub loot_matrix: Option<LootMatrixTable<'db>>,
     /// LootTable
     pub loot_table: LootTableTable<'db>,
     /// Missions
@@ -143,72 +367,488 @@ pub struct TypedDatabase<'db> {
     pub mission_tasks: MissionTasksTable<'db>,
     /// Objects
     pub objects: ObjectsTable<'db>,
-    /// Objects
+    /// ObjectSkills
     pub object_skills: ObjectSkillsTable<'db>,
     /// RebuildComponent
     pub rebuild_component: RebuildComponentTable<'db>,
+    /// Release_Version (optional)
+    pub release_version: Option<ReleaseVersionTable<'db>>,
     /// RenderComponent
     pub render_comp: RenderComponentTable<'db>,
     /// SkillBehavior
     pub skills: SkillBehaviorTable<'db>,
+    tables: Tables<'db>,
+    names: TableNameMap,
+    byte_len: Option<usize>,
+}
+
+/// An error when loading a [`TypedDatabase`]
+#[derive(Debug)]
+pub enum LoadError {
+    /// The FDB file is malformed
+    Cast(CastError),
+    /// A required table is not present
+    ///
+    /// This is the name that was searched for, after applying the [`TableNameMap`].
+    MissingTable(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cast(e) => write!(f, "malformed FDB: {}", e),
+            Self::MissingTable(name) => write!(f, "missing table '{}'", name),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+impl From<CastError> for LoadError {
+    fn from(e: CastError) -> Self {
+        Self::Cast(e)
+    }
+}
+
+/// Overrides for the names of the well-known tables
+///
+/// Some variants of the database use different names for the tables, e.g.
+/// `cdclient_Missions` instead of `Missions`.
+#[derive(Debug, Clone, Default)]
+pub struct TableNameMap {
+    names: HashMap<TableName, String>,
+}
+
+impl TableNameMap {
+    /// Create a new map without any overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `name` for the table that is usually called `default`
+    pub fn insert(&mut self, default: TableName, name: String) -> Option<String> {
+        self.names.insert(default, name)
+    }
+
+    /// Get the name to use for the table that is usually called `default`
+    pub fn get<'s>(&'s self, default: &'s str) -> &'s str {
+        self.names.get(default).map_or(default, String::as_str)
+    }
+}
+
+fn required_table<'a>(
+    tables: &Tables<'a>,
+    names: &TableNameMap,
+    default: TableName,
+) -> Result<Table<'a>, LoadError> {
+    let name = names.get(default);
+    let table = tables
+        .by_name(name)
+        .ok_or_else(|| LoadError::MissingTable(name.to_owned()))??;
+    Ok(table)
 }
 
 fn is_not_empty(s: &&Latin1Str) -> bool {
     !s.is_empty()
 }
 
+/// Get all rows of a table that may have `id` in the first column
+///
+/// Only the bucket that `id` hashes to needs to be checked, see [`key_hash`]. Tables
+/// with a key column of any other type fall back to scanning the entire table.
+pub(crate) fn key_rows<'a>(table: Table<'a>, id: i32) -> KeyRows<'a> {
+    if table.bucket_count() == 0 {
+        KeyRows::Empty
+    } else if let Some(hash) = key_hash(table, id) {
+        KeyRows::Bucket(table.bucket_for_hash(hash).row_iter())
+    } else {
+        KeyRows::Scan(table.row_iter())
+    }
+}
+
+/// # Iterator over the raw rows that may have a specific key
+///
+/// See [`TypedTable::bucket_rows`]
+pub enum KeyRows<'a> {
+    /// The table has no buckets
+    Empty,
+    /// The rows in the bucket that the key hashes to
+    Bucket(assembly_fdb::mem::iter::RowHeaderIter<'a>),
+    /// All rows of a table whose key column can't be hashed
+    Scan(assembly_fdb::mem::iter::TableRowIter<'a>),
+}
+
+impl<'a> Iterator for KeyRows<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Empty => None,
+            Self::Bucket(rows) => rows.next(),
+            Self::Scan(rows) => rows.next(),
+        }
+    }
+}
+
+/// Get the hash that the FDB uses for `id` in the first column of `table`
+///
+/// Integer keys are hashed by their value, reinterpreted as a `u32`. Some variants
+/// of the database use text keys instead, which are hashed with sdbm, so this hashes
+/// the decimal representation of `id`. Text keys that are not written that way, e.g.
+/// with leading zeros, are not found. Returns `None` for any other key type.
+pub(crate) fn key_hash(table: Table, id: i32) -> Option<u32> {
+    match table.column_iter().next().map(|col| col.value_type()) {
+        Some(ValueType::Integer) => Some(u32::from_ne_bytes(id.to_ne_bytes())),
+        Some(ValueType::Text) => Some(sdbm(id.to_string().as_bytes())),
+        _ => None,
+    }
+}
+
+/// The sdbm hash that the FDB uses for text keys
+fn sdbm(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0u32, |hash, &b| {
+        u32::from(b)
+            .wrapping_add(hash << 6)
+            .wrapping_add(hash << 16)
+            .wrapping_sub(hash)
+    })
+}
+
+/// Get the index of the bucket for a hash
+///
+/// This is the same bucket as [`Table::bucket_for_hash`]. `bucket_count` must not be `0`.
+pub(crate) fn bucket_index(hash: u32, bucket_count: usize) -> usize {
+    hash as usize % bucket_count
+}
+
+/// Check whether the first field of a row is equal to `id`
+pub(crate) fn has_key(row: &Row, id: i32) -> bool {
+    row.field_at(0).is_some_and(|field| key_matches(field, id))
+}
+
+/// Check whether a key field is equal to `id`
+pub(crate) fn key_matches(field: Field, id: i32) -> bool {
+    field_key(field) == Some(id)
+}
+
+/// Get the integer value of a key field
+///
+/// Text keys are parsed from their decimal representation.
+pub(crate) fn field_key(field: Field) -> Option<i32> {
+    match field {
+        Value::Integer(key) => Some(key),
+        Value::Text(key) => key.decode().trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Call `f` for every row of `table` that has one of `keys` in the first column
+///
+/// `f` receives the position of the key in `keys`. The keys are grouped by bucket,
+/// so that every bucket is only walked once.
+pub(crate) fn batch_rows<'a>(table: Table<'a>, keys: &[i32], mut f: impl FnMut(usize, Row<'a>)) {
+    let bucket_count = table.bucket_count();
+    if bucket_count == 0 {
+        return;
+    }
+
+    let mut positions: HashMap<i32, Vec<usize>> = HashMap::new();
+    for (pos, &key) in keys.iter().enumerate() {
+        positions.entry(key).or_default().push(pos);
+    }
+
+    let mut visit = |row: Row<'a>| {
+        let key = row.field_at(0).and_then(field_key);
+        if let Some(found) = key.and_then(|key| positions.get(&key)) {
+            for &pos in found {
+                f(pos, row);
+            }
+        }
+    };
+
+    let buckets: Option<BTreeSet<usize>> = positions
+        .keys()
+        .map(|&key| Some(bucket_index(key_hash(table, key)?, bucket_count)))
+        .collect();
+    if let Some(buckets) = buckets {
+        for index in buckets {
+            if let Some(bucket) = table.bucket_at(index) {
+                bucket.row_iter().for_each(&mut visit);
+            }
+        }
+    } else {
+        table.row_iter().for_each(visit);
+    }
+}
+
 impl<'a> TypedDatabase<'a> {
+    /// Construct a new typed database from the bytes of an FDB file
+    pub fn from_bytes(buf: &'a [u8]) -> Result<Self, LoadError> {
+        let tables = Database::new(buf).tables()?;
+        let mut db = Self::new(tables)?;
+        db.byte_len = Some(buf.len());
+        Ok(db)
+    }
+
     /// Construct a new typed database
-    pub fn new(tables: Tables<'a>) -> Result<Self, CastError> {
-        let behavior_parameter_inner = tables.by_name("BehaviorParameter").unwrap()?;
-        let behavior_template_inner = tables.by_name("BehaviorTemplate").unwrap()?;
-        let components_registry_inner = tables.by_name("ComponentsRegistry").unwrap()?;
-        let destructible_component_inner = tables.by_name("DestructibleComponent").unwrap()?;
-        let icons_inner = tables.by_name("Icons").unwrap()?;
-        let item_sets_inner = tables.by_name("ItemSets").unwrap()?;
-        let item_set_skills_inner = tables.by_name("ItemSetSkills").unwrap()?;
-        let loot_table_inner = tables.by_name("LootTable").unwrap()?;
-        let missions_inner = tables.by_name("Missions").unwrap()?;
-        let mission_tasks_inner = tables.by_name("MissionTasks").unwrap()?;
-        let objects_inner = tables.by_name("Objects").unwrap()?;
-        let object_skills_inner = tables.by_name("ObjectSkills").unwrap()?;
-        let rebuild_component_inner = tables.by_name("RebuildComponent").unwrap()?;
-        let render_component_inner = tables.by_name("RenderComponent").unwrap()?;
-        let skill_behavior_inner = tables.by_name("SkillBehavior").unwrap()?;
+    pub fn new(tables: Tables<'a>) -> Result<Self, LoadError> {
+        Self::new_with_names(tables, &TableNameMap::new())
+    }
+
+    /// Construct a new typed database, where some tables may have been renamed
+    ///
+    /// Tables that have no entry in `names` use their usual name.
+    pub fn new_with_names(tables: Tables<'a>, names: &TableNameMap) -> Result<Self, LoadError> {
+        let activity_rewards_inner = tables.by_name(names.get("ActivityRewards")).transpose()?;
+        let behavior_parameter_inner = required_table(&tables, names, "BehaviorParameter")?;
+        let behavior_template_inner = required_table(&tables, names, "BehaviorTemplate")?;
+        let components_registry_inner = required_table(&tables, names, "ComponentsRegistry")?;
+        let destructible_component_inner = required_table(&tables, names, "DestructibleComponent")?;
+        let emotes_inner = tables.by_name(names.get("Emotes")).transpose()?;
+        let icons_inner = required_table(&tables, names, "Icons")?;
+        let item_sets_inner = required_table(&tables, names, "ItemSets")?;
+        let item_set_skills_inner = required_table(&tables, names, "ItemSetSkills")?;
+        let loot_matrix_inner = tables.by_name(names.get("LootMatrix")).transpose()?;
+        let loot_table_inner = required_table(&tables, names, "LootTable")?;
+        let missions_inner = required_table(&tables, names, "Missions")?;
+        let mission_tasks_inner = required_table(&tables, names, "MissionTasks")?;
+        let objects_inner = required_table(&tables, names, "Objects")?;
+        let object_skills_inner = required_table(&tables, names, "ObjectSkills")?;
+        let rebuild_component_inner = required_table(&tables, names, "RebuildComponent")?;
+        let release_version_inner = tables.by_name(names.get("Release_Version")).transpose()?;
+        let render_component_inner = required_table(&tables, names, "RenderComponent")?;
+        let skill_behavior_inner = required_table(&tables, names, "SkillBehavior")?;
         Ok(TypedDatabase {
+            activity_rewards: activity_rewards_inner.map(ActivityRewardsTable::new),
             behavior_parameters: BehaviorParameterTable::new(behavior_parameter_inner),
             behavior_templates: BehaviorTemplateTable::new(behavior_template_inner),
             comp_reg: ComponentsRegistryTable::new(components_registry_inner),
             destructible_component: DestructibleComponentTable::new(destructible_component_inner),
+            emotes: emotes_inner.map(EmotesTable::new),
             icons: IconsTable::new(icons_inner),
             item_sets: ItemSetsTable::new(item_sets_inner),
             item_set_skills: ItemSetSkillsTable::new(item_set_skills_inner),
+            loot_matrix: loot_matrix_inner.map(LootMatrixTable::new),
             loot_table: LootTableTable::new(loot_table_inner),
             missions: MissionsTable::new(missions_inner),
             mission_tasks: MissionTasksTable::new(mission_tasks_inner),
             objects: ObjectsTable::new(objects_inner),
             object_skills: ObjectSkillsTable::new(object_skills_inner),
             rebuild_component: RebuildComponentTable::new(rebuild_component_inner),
+            release_version: release_version_inner.map(ReleaseVersionTable::new),
             render_comp: RenderComponentTable::new(render_component_inner),
             skills: SkillBehaviorTable::new(skill_behavior_inner),
+            tables,
+            names: names.clone(),
+            byte_len: None,
         })
     }
 
+    /// Get the size of the FDB buffer this database reads from
+    ///
+    /// [`Tables`] does not expose the underlying buffer, so this is only known
+    /// when the database was created with [`TypedDatabase::from_bytes`] and `None` otherwise.
+    pub fn byte_len(&self) -> Option<usize> {
+        self.byte_len
+    }
+
+    /// Get a single field from any table in the FDB, by name
+    ///
+    /// This looks up the rows with `pk` in the first column of `table` and returns
+    /// the value in the column named `column` of the first one. It does not need any
+    /// compile-time knowledge of the table, so it also works for tables that are not
+    /// part of this struct. Returns `None` if the table, row or column do not exist.
+    /// Table names are mapped with the [`TableNameMap`] the database was created with.
+    pub fn get_field(&self, table: &str, pk: i32, column: &str) -> Option<Field<'a>> {
+        let table = self.tables.by_name(self.names.get(table))?.ok()?;
+        let index = table
+            .column_iter()
+            .position(|col| col.name_raw().as_bytes() == column.as_bytes())?;
+        key_rows(table, pk)
+            .find(|row| has_key(row, pk))
+            .and_then(|row| row.field_at(index))
+    }
+
+    /// Check whether the FDB has a table with the specified name
+    ///
+    /// The name is mapped with the [`TableNameMap`] the database was created with.
+    pub fn contains_table(&self, name: &str) -> bool {
+        matches!(self.tables.by_name(self.names.get(name)), Some(Ok(_)))
+    }
+
+    /// Call `f` with the raw table of the specified name, if it is present
+    ///
+    /// Returns the result of `f`, or `None` if the table does not exist. The name is
+    /// mapped with the [`TableNameMap`] the database was created with.
+    pub fn visit_table<R>(&self, name: &str, f: impl FnOnce(Table<'a>) -> R) -> Option<R> {
+        let table = self.tables.by_name(self.names.get(name))?.ok()?;
+        Some(f(table))
+    }
+
+    /// Count the rows in every table
+    ///
+    /// This walks every table once, so it is meant for logging at startup rather than
+    /// for every request.
+    pub fn stats(&self) -> DatabaseStats {
+        let mut tables = BTreeMap::new();
+        let mut count = |name: TableName, table: Table| {
+            tables.insert(name, table.row_iter().count());
+        };
+        if let Some(table) = &self.activity_rewards {
+            count(ActivityRewardsTable::NAME, table.as_raw());
+        }
+        count(
+            BehaviorParameterTable::NAME,
+            self.behavior_parameters.as_raw(),
+        );
+        count(
+            BehaviorTemplateTable::NAME,
+            self.behavior_templates.as_raw(),
+        );
+        count(ComponentsRegistryTable::NAME, self.comp_reg.as_raw());
+        count(
+            DestructibleComponentTable::NAME,
+            self.destructible_component.as_raw(),
+        );
+        if let Some(table) = &self.emotes {
+            count(EmotesTable::NAME, table.as_raw());
+        }
+        count(IconsTable::NAME, self.icons.as_raw());
+        count(ItemSetsTable::NAME, self.item_sets.as_raw());
+        count(ItemSetSkillsTable::NAME, self.item_set_skills.as_raw());
+        if let Some(table) = &self.loot_matrix {
+            count(LootMatrixTable::NAME, table.as_raw());
+        }
+        count(LootTableTable::NAME, self.loot_table.as_raw());
+        count(MissionsTable::NAME, self.missions.as_raw());
+        count(MissionTasksTable::NAME, self.mission_tasks.as_raw());
+        count(ObjectsTable::NAME, self.objects.as_raw());
+        count(ObjectSkillsTable::NAME, self.object_skills.as_raw());
+        count(RebuildComponentTable::NAME, self.rebuild_component.as_raw());
+        if let Some(table) = &self.release_version {
+            count(ReleaseVersionTable::NAME, table.as_raw());
+        }
+        count(RenderComponentTable::NAME, self.render_comp.as_raw());
+        count(SkillBehaviorTable::NAME, self.skills.as_raw());
+        let total = tables.values().sum();
+        DatabaseStats { tables, total }
+    }
+
+    /// Try to resolve every well-known column in every table
+    ///
+    /// This never panics, so it can be used to check an FDB before relying on the
+    /// accessors. Optional tables that are not present are skipped.
+    pub fn resolve_all_columns(&self) -> Vec<(TableName, ColumnName, bool)> {
+        let mut resolved = Vec::new();
+        if let Some(table) = &self.activity_rewards {
+            resolved.extend(table.resolve_columns());
+        }
+        resolved.extend(self.behavior_parameters.resolve_columns());
+        resolved.extend(self.behavior_templates.resolve_columns());
+        resolved.extend(self.comp_reg.resolve_columns());
+        resolved.extend(self.destructible_component.resolve_columns());
+        if let Some(table) = &self.emotes {
+            resolved.extend(table.resolve_columns());
+        }
+        resolved.extend(self.icons.resolve_columns());
+        resolved.extend(self.item_sets.resolve_columns());
+        resolved.extend(self.item_set_skills.resolve_columns());
+        if let Some(table) = &self.loot_matrix {
+            resolved.extend(table.resolve_columns());
+        }
+        resolved.extend(self.loot_table.resolve_columns());
+        resolved.extend(self.missions.resolve_columns());
+        resolved.extend(self.mission_tasks.resolve_columns());
+        resolved.extend(self.objects.resolve_columns());
+        resolved.extend(self.object_skills.resolve_columns());
+        resolved.extend(self.rebuild_component.resolve_columns());
+        if let Some(table) = &self.release_version {
+            resolved.extend(table.resolve_columns());
+        }
+        resolved.extend(self.render_comp.resolve_columns());
+        resolved.extend(self.skills.resolve_columns());
+        resolved
+    }
+
+    /// Infer the client version this database belongs to
+    ///
+    /// This prefers the most recent entry of the `Release_Version` table, if present,
+    /// and otherwise checks for columns that were introduced with the 1.10.64 client.
+    /// Returns `None` if neither is conclusive.
+    pub fn detect_version(&self) -> Option<GateVersion> {
+        if let Some(release_version) = &self.release_version {
+            let latest = release_version
+                .row_iter()
+                .max_by_key(|row| row.try_release_date());
+            if let Some(version) = latest.and_then(|row| row.try_release_version()) {
+                return Some(GateVersion::Release(version.decode().into_owned()));
+            }
+        }
+
+        let has_hq_valid = self.objects.get_col(ObjectsColumn::HqValid).is_some();
+        let has_bank_inventory = self
+            .missions
+            .get_col(MissionsColumn::RewardBankinventory)
+            .is_some();
+        if has_hq_valid && has_bank_inventory {
+            return Some(GateVersion::V1_10_64);
+        }
+        None
+    }
+
     /// Get the path of an icon ID
     pub fn get_icon_path(&self, id: i32) -> Option<&Latin1Str> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let bucket = self.icons.as_raw().bucket_for_hash(hash);
+        let rows = key_rows(self.icons.as_raw(), id);
+
+        let col_icon_path = self.icons.get_col(IconsColumn::IconPath)?;
+
+        for row in rows {
+            if has_key(&row, id) {
+                return row.field_at(col_icon_path)?.into_opt_text();
+            }
+        }
+        None
+    }
 
-        let col_icon_path = self
-            .icons
-            .get_col(IconsColumn::IconPath)
-            .expect("Missing column 'Icons::IconPath'");
+    /// Get the paths for many icon IDs at once
+    ///
+    /// Like [`TypedDatabase::get_icon_path`], but every bucket of the `Icons` table is
+    /// only walked once. IDs that do not resolve are not included in the result.
+    pub fn get_icon_paths(&self, ids: &[i32]) -> HashMap<i32, &'a Latin1Str> {
+        let mut paths = HashMap::new();
+        let col_icon_path = match self.icons.get_col(IconsColumn::IconPath) {
+            Some(index) => index,
+            None => return paths,
+        };
+        batch_rows(self.icons.as_raw(), ids, |pos, row| {
+            if let Some(path) = row.field_at(col_icon_path).and_then(|f| f.into_opt_text()) {
+                paths.entry(ids[pos]).or_insert(path);
+            }
+        });
+        paths
+    }
 
-        for row in bucket.row_iter() {
-            let id_field = row.field_at(0).unwrap();
+    /// Get the icon and label of the specified emote
+    ///
+    /// Returns `None` if the FDB has no `Emotes` table.
+    pub fn get_emote_display(&self, id: i32) -> Option<EmoteDisplay> {
+        let emotes = self.emotes.as_ref()?;
+        let col_icon_filename = emotes.get_col(EmotesColumn::IconFilename);
+        let col_command = emotes.get_col(EmotesColumn::Command);
 
-            if id_field == Value::Integer(id) {
-                return row.field_at(col_icon_path).unwrap().into_opt_text();
+        for row in key_rows(emotes.as_raw(), id) {
+            if has_key(&row, id) {
+                let text = |col: Option<usize>| {
+                    col.and_then(|index| row.field_at(index))
+                        .and_then(|field| field.into_opt_text())
+                        .filter(is_not_empty)
+                };
+                return Some(EmoteDisplay {
+                    id,
+                    icon_filename: text(col_icon_filename),
+                    command: text(col_command),
+                    locale_prefix: format!("Emotes_{}", id),
+                });
             }
         }
         None
@@ -216,30 +856,19 @@ impl<'a> TypedDatabase<'a> {
 
     /// Get data for the specified mission ID
     pub fn get_mission_data(&self, id: i32) -> Option<Mission> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let bucket = self.missions.as_raw().bucket_for_hash(hash);
+        let rows = key_rows(self.missions.as_raw(), id);
 
-        let col_mission_icon_id = self
-            .missions
-            .get_col(MissionsColumn::MissionIconId)
-            .expect("Missing column 'Missions::mission_icon_id'");
-        let col_is_mission = self
-            .missions
-            .get_col(MissionsColumn::IsMission)
-            .expect("Missing column 'Missions::is_mission'");
+        let col_mission_icon_id = self.missions.get_col(MissionsColumn::MissionIconId)?;
+        let col_is_mission = self.missions.get_col(MissionsColumn::IsMission)?;
 
-        for row in bucket.row_iter() {
-            let id_field = row.field_at(0).unwrap();
-
-            if id_field == Value::Integer(id) {
+        for row in rows {
+            if has_key(&row, id) {
                 let mission_icon_id = row
                     .field_at(col_mission_icon_id)
-                    .unwrap()
-                    .into_opt_integer();
+                    .and_then(|field| field.into_opt_integer());
                 let is_mission = row
                     .field_at(col_is_mission)
-                    .unwrap()
-                    .into_opt_boolean()
+                    .and_then(|field| field.into_opt_boolean())
                     .unwrap_or(true);
 
                 return Some(Mission {
@@ -251,83 +880,143 @@ impl<'a> TypedDatabase<'a> {
         None
     }
 
+    /// Get the IDs of the missions referenced in the prerequisites of the specified mission
+    ///
+    /// The `prereqMissionID` column contains an expression like `(1|2),3`. This
+    /// ignores the operators and returns every referenced mission once, in order of
+    /// appearance. Suffixes like the `:2` in `5:2` are skipped as well.
+    pub fn get_mission_prerequisites(&self, id: i32) -> Vec<i32> {
+        let col_prereq = match self.missions.get_col(MissionsColumn::PrereqMissionId) {
+            Some(index) => index,
+            None => return Vec::new(),
+        };
+        let prereq = key_rows(self.missions.as_raw(), id)
+            .find(|row| has_key(row, id))
+            .and_then(|row| row.field_at(col_prereq))
+            .and_then(|field| field.into_opt_text());
+
+        let mut ids = Vec::new();
+        if let Some(prereq) = prereq {
+            let prereq = prereq.decode();
+            for token in prereq.split(|c: char| !c.is_ascii_digit() && c != ':') {
+                let mission_id = token.split(':').next().and_then(|id| id.parse().ok());
+                if let Some(mission_id) = mission_id {
+                    if !ids.contains(&mission_id) {
+                        ids.push(mission_id);
+                    }
+                }
+            }
+        }
+        ids
+    }
+
     /// Get a list of mission tasks for the specified mission ID
     pub fn get_mission_tasks(&self, id: i32) -> Vec<MissionTask> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let bucket = self.mission_tasks.as_raw().bucket_for_hash(hash);
+        let rows = key_rows(self.mission_tasks.as_raw(), id);
         let mut tasks = Vec::with_capacity(4);
 
-        let col_icon_id = self
-            .mission_tasks
-            .get_col(MissionTasksColumn::IconId)
-            .expect("Missing column 'MissionTasks::icon_id'");
-        let col_uid = self
-            .mission_tasks
-            .get_col(MissionTasksColumn::Uid)
-            .expect("Missing column 'MissionTasks::uid'");
-
-        for row in bucket.row_iter() {
-            let id_field = row.field_at(0).unwrap();
+        let col_icon_id = self.mission_tasks.get_col(MissionTasksColumn::IconId);
+        let col_uid = match self.mission_tasks.get_col(MissionTasksColumn::Uid) {
+            Some(col_uid) => col_uid,
+            None => return tasks,
+        };
 
-            if id_field == Value::Integer(id) {
-                let icon_id = row.field_at(col_icon_id).unwrap().into_opt_integer();
-                let uid = row.field_at(col_uid).unwrap().into_opt_integer().unwrap();
+        for row in rows {
+            if has_key(&row, id) {
+                let icon_id = col_icon_id
+                    .and_then(|index| row.field_at(index))
+                    .and_then(|field| field.into_opt_integer());
+                let uid = row
+                    .field_at(col_uid)
+                    .and_then(|field| field.into_opt_integer());
 
-                tasks.push(MissionTask { icon_id, uid })
+                if let Some(uid) = uid {
+                    tasks.push(MissionTask { icon_id, uid })
+                }
             }
         }
         tasks
     }
 
+    /// Check whether an object with the specified LOT exists
+    ///
+    /// This only reads the key of the rows in the bucket, so it is much cheaper
+    /// than [`TypedDatabase::get_object_name_desc`].
+    pub fn object_exists(&self, lot: i32) -> bool {
+        key_rows(self.objects.as_raw(), lot).any(|row| has_key(&row, lot))
+    }
+
     /// Get the name and description for the specified LOT
     pub fn get_object_name_desc(&self, id: i32) -> Option<(String, String)> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
+        self.object_name_desc(id, None)
+    }
 
-        let table = self.objects.as_raw();
-        let bucket = table
-            .bucket_at(hash as usize % table.bucket_count())
-            .unwrap();
+    /// Get the name and description for the specified LOT, preferring localized strings
+    ///
+    /// This looks up the keys `Objects_<id>_name` and `Objects_<id>_description` and
+    /// falls back to the text in the `Objects` table for each one that is missing.
+    pub fn get_localized_object_name_desc(
+        &self,
+        id: i32,
+        locale: &dyn Localize,
+    ) -> Option<(String, String)> {
+        self.object_name_desc(id, Some(locale))
+    }
+
+    fn object_name_desc(&self, id: i32, locale: Option<&dyn Localize>) -> Option<(String, String)> {
+        let localized =
+            |column: &str| locale.and_then(|l| l.localize(&format!("Objects_{}_{}", id, column)));
+        let rows = key_rows(self.objects.as_raw(), id);
 
-        for row in bucket.row_iter() {
+        for row in rows {
             let mut fields = row.field_iter();
-            let id_field = fields.next().unwrap();
-            if id_field == Value::Integer(id) {
-                let name = fields.next().unwrap(); // 1: name
-                let description = fields.nth(2).unwrap(); // 4: description
-                let display_name = fields.nth(2).unwrap(); // 7: displayName
-                let internal_notes = fields.nth(2).unwrap(); // 10: internalNotes
-
-                let title = match (
-                    name.into_opt_text().filter(is_not_empty),
-                    display_name.into_opt_text().filter(is_not_empty),
-                ) {
-                    (Some(name), Some(display)) if display != name => {
-                        format!("{} ({}) | Object #{}", display.decode(), name.decode(), id)
-                    }
-                    (Some(name), _) => {
-                        format!("{} | Object #{}", name.decode(), id)
-                    }
-                    (None, Some(display)) => {
-                        format!("{} | Object #{}", display.decode(), id)
-                    }
-                    (None, None) => {
-                        format!("Object #{}", id)
+            if fields.next().is_some_and(|field| key_matches(field, id)) {
+                let name = fields.next()?; // 1: name
+                let description = fields.nth(2)?; // 4: description
+                let display_name = fields.nth(2)?; // 7: displayName
+                let internal_notes = fields.nth(2)?; // 10: internalNotes
+
+                let title = if let Some(name) = localized("name") {
+                    format!("{} | Object #{}", name, id)
+                } else {
+                    match (
+                        name.into_opt_text().filter(is_not_empty),
+                        display_name.into_opt_text().filter(is_not_empty),
+                    ) {
+                        (Some(name), Some(display)) if display != name => {
+                            format!("{} ({}) | Object #{}", display.decode(), name.decode(), id)
+                        }
+                        (Some(name), _) => {
+                            format!("{} | Object #{}", name.decode(), id)
+                        }
+                        (None, Some(display)) => {
+                            format!("{} | Object #{}", display.decode(), id)
+                        }
+                        (None, None) => {
+                            format!("Object #{}", id)
+                        }
                     }
                 };
-                let desc = match (
-                    description.into_opt_text().filter(is_not_empty),
-                    internal_notes.into_opt_text().filter(is_not_empty),
-                ) {
-                    (Some(description), Some(internal_notes)) if description != internal_notes => {
-                        format!("{} ({})", description.decode(), internal_notes.decode(),)
-                    }
-                    (Some(description), _) => {
-                        format!("{}", description.decode())
-                    }
-                    (None, Some(internal_notes)) => {
-                        format!("{}", internal_notes.decode())
+                let desc = if let Some(desc) = localized("description") {
+                    desc
+                } else {
+                    match (
+                        description.into_opt_text().filter(is_not_empty),
+                        internal_notes.into_opt_text().filter(is_not_empty),
+                    ) {
+                        (Some(description), Some(internal_notes))
+                            if description != internal_notes =>
+                        {
+                            format!("{} ({})", description.decode(), internal_notes.decode(),)
+                        }
+                        (Some(description), _) => {
+                            format!("{}", description.decode())
+                        }
+                        (None, Some(internal_notes)) => {
+                            format!("{}", internal_notes.decode())
+                        }
+                        (None, None) => String::new(),
                     }
-                    (None, None) => String::new(),
                 };
                 return Some((title, desc));
             }
@@ -336,50 +1025,452 @@ impl<'a> TypedDatabase<'a> {
     }
 
     /// Get the path of the icon asset of the specified render component
-    pub fn get_render_image(&self, id: i32) -> Option<&Latin1Str> {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let table = self.render_comp.as_raw();
-        let bucket = table
-            .bucket_at(hash as usize % table.bucket_count())
-            .unwrap();
-
-        for row in bucket.row_iter() {
-            let mut fields = row.field_iter();
-            let id_field = fields.next().unwrap();
-            if id_field == Value::Integer(id) {
-                let _render_asset = fields.next().unwrap();
-                let icon_asset = fields.next().unwrap();
+    pub fn get_render_image(&self, id: i32) -> Option<&'a Latin1Str> {
+        let col_icon_asset = self.render_comp.get_col(RenderComponentColumn::IconAsset)?;
+        key_rows(self.render_comp.as_raw(), id)
+            .find(|row| has_key(row, id))
+            .and_then(|row| row.field_at(col_icon_asset))
+            .and_then(|field| field.into_opt_text())
+    }
 
-                if let Value::Text(url) = icon_asset {
-                    return Some(url);
-                }
-            }
-        }
-        None
+    /// Get the image path of the render component of the specified LOT
+    ///
+    /// Returns `None` if the object has no render component.
+    pub fn get_render_image_for_object(&self, lot: i32) -> Option<&'a Latin1Str> {
+        let render_id = self.get_components(lot).render?;
+        self.get_render_image(render_id)
+    }
+
+    /// Get the types of all components of the specified LOT
+    ///
+    /// The types are in the order of the `ComponentsRegistry` table. Rows without an
+    /// integer component type are skipped.
+    pub fn get_component_types(&self, lot: i32) -> Vec<ComponentType> {
+        self.comp_reg
+            .key_iter(lot)
+            .filter_map(|row| row.try_component_type())
+            .map(ComponentType::from)
+            .collect()
     }
 
     /// Get all components for the specified LOT
+    ///
+    /// If the object has more than one component of a type, the first one is used.
     pub fn get_components(&self, id: i32) -> Components {
-        let hash = u32::from_ne_bytes(id.to_ne_bytes());
-        let table = self.comp_reg.as_raw();
-        let bucket = table
-            .bucket_at(hash as usize % table.bucket_count())
-            .unwrap();
+        let rows = key_rows(self.comp_reg.as_raw(), id);
 
         let mut comp = Components::default();
 
-        for row in bucket.row_iter() {
-            let mut fields = row.field_iter();
-            let id_field = fields.next().unwrap();
-            if id_field == Value::Integer(id) {
-                let component_type = fields.next().unwrap();
-                let component_id = fields.next().unwrap();
+        let col_component_type = self
+            .comp_reg
+            .get_col(ComponentsRegistryColumn::ComponentType);
+        let col_component_id = self.comp_reg.get_col(ComponentsRegistryColumn::ComponentId);
+        let (col_component_type, col_component_id) = match (col_component_type, col_component_id) {
+            (Some(ty), Some(id)) => (ty, id),
+            _ => return comp,
+        };
 
-                if let Value::Integer(2) = component_type {
-                    comp.render = component_id.into_opt_integer();
+        for row in rows {
+            if !has_key(&row, id) {
+                continue;
+            }
+            // Rows that are too short to have both fields are skipped
+            if let (Some(Value::Integer(ty)), Some(Value::Integer(component_id))) = (
+                row.field_at(col_component_type),
+                row.field_at(col_component_id),
+            ) {
+                if ComponentType::from(ty) == ComponentType::Render {
+                    comp.render.get_or_insert(component_id);
                 }
             }
         }
         comp
     }
+
+    /// Resolve many objects at once
+    ///
+    /// This returns the same data as the individual lookups, but walks every bucket
+    /// of the `Objects`, `ComponentsRegistry` and `RenderComponent` tables only once.
+    /// The result has one entry per LOT in `lots`, which is `None` if the object does
+    /// not exist. Like the individual lookups, the first row wins if a key appears
+    /// more than once in a table.
+    pub fn get_objects_batch(&self, lots: &[i32]) -> Vec<Option<ResolvedObject<'a>>> {
+        let mut resolved: Vec<Option<ResolvedObject<'a>>> = vec![None; lots.len()];
+
+        let col_name = self.objects.get_col(ObjectsColumn::Name);
+        batch_rows(self.objects.as_raw(), lots, |pos, row| {
+            if resolved[pos].is_some() {
+                return;
+            }
+            let name = col_name
+                .and_then(|index| row.field_at(index))
+                .and_then(|field| field.into_opt_text());
+            resolved[pos] = Some(ResolvedObject {
+                id: lots[pos],
+                name,
+                components: Components::default(),
+                render_image: None,
+            });
+        });
+
+        let col_component_type = self
+            .comp_reg
+            .get_col(ComponentsRegistryColumn::ComponentType);
+        let col_component_id = self.comp_reg.get_col(ComponentsRegistryColumn::ComponentId);
+        batch_rows(self.comp_reg.as_raw(), lots, |pos, row| {
+            let int = |col: Option<usize>| {
+                col.and_then(|index| row.field_at(index))
+                    .and_then(|field| field.into_opt_integer())
+            };
+            if let Some(object) = &mut resolved[pos] {
+                let ty = int(col_component_type).map(ComponentType::from);
+                if let (Some(ComponentType::Render), Some(component_id)) =
+                    (ty, int(col_component_id))
+                {
+                    object.components.render.get_or_insert(component_id);
+                }
+            }
+        });
+
+        let (owners, render_ids): (Vec<usize>, Vec<i32>) = resolved
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, object)| Some((pos, object.as_ref()?.components.render?)))
+            .unzip();
+        let col_icon_asset = self.render_comp.get_col(RenderComponentColumn::IconAsset);
+        let mut found = vec![false; render_ids.len()];
+        batch_rows(self.render_comp.as_raw(), &render_ids, |pos, row| {
+            if std::mem::replace(&mut found[pos], true) {
+                return;
+            }
+            if let Some(object) = &mut resolved[owners[pos]] {
+                object.render_image = col_icon_asset
+                    .and_then(|index| row.field_at(index))
+                    .and_then(|field| field.into_opt_text());
+            }
+        });
+
+        resolved
+    }
+
+    /// Get the activity rewards for the specified rebuild component
+    ///
+    /// This follows the `activityID` of the rebuild component to the `ActivityRewards` table,
+    /// and resolves the items of every reward through its loot matrix. The result is empty
+    /// if the FDB has no `ActivityRewards` table.
+    pub fn get_rebuild_rewards(&self, rebuild_component_id: i32) -> Vec<ActivityReward> {
+        let activity_id = self
+            .rebuild_component
+            .key_iter(rebuild_component_id)
+            .next()
+            .and_then(|row| row.activity_id());
+        match (activity_id, &self.activity_rewards) {
+            (Some(activity_id), Some(activity_rewards)) => activity_rewards
+                .key_iter(activity_id)
+                .filter_map(|row| {
+                    let loot_matrix_index = row.loot_matrix_index();
+                    Some(ActivityReward {
+                        activity_rating: row.try_activity_rating()?,
+                        loot_matrix_index,
+                        currency_index: row.currency_index(),
+                        challenge_rating: row.try_challenge_rating()?,
+                        drops: loot_matrix_index
+                            .map(|index| self.loot_matrix_drops(index))
+                            .unwrap_or_default(),
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get all items that may drop when the specified LOT is smashed
+    ///
+    /// This follows the `DestructibleComponent` of the object to its loot matrix and
+    /// resolves every loot table in it. The result is empty if the object is not
+    /// destructible, has no loot matrix or the FDB has no `LootMatrix` table.
+    pub fn get_object_drops(&self, lot: i32) -> Vec<LootDrop> {
+        let loot_matrix_index = self
+            .comp_reg
+            .key_iter(lot)
+            .find(|row| {
+                row.try_component_type().map(ComponentType::from)
+                    == Some(ComponentType::Destructible)
+            })
+            .and_then(|row| row.try_component_id())
+            .and_then(|id| self.destructible_component.key_iter(id).next())
+            .and_then(|row| row.loot_matrix_index());
+        match loot_matrix_index {
+            Some(index) => self.loot_matrix_drops(index),
+            None => Vec::new(),
+        }
+    }
+
+    /// Get all items in the specified loot matrix
+    ///
+    /// This resolves every loot table in the matrix through the `LootTable` table.
+    fn loot_matrix_drops(&self, loot_matrix_index: i32) -> Vec<LootDrop> {
+        let loot_matrix = match &self.loot_matrix {
+            Some(table) => table,
+            None => return Vec::new(),
+        };
+        let entries: Vec<_> = loot_matrix.key_iter(loot_matrix_index).collect();
+        if entries.is_empty() {
+            return Vec::new();
+        }
+
+        // `LootTable` is keyed by item, so all loot tables are collected in a single scan
+        let mut items: HashMap<i32, Vec<(i32, bool)>> = entries
+            .iter()
+            .filter_map(|entry| Some((entry.try_loot_table_index()?, Vec::new())))
+            .collect();
+        for row in self.loot_table.row_iter() {
+            let table_items = row
+                .try_loot_table_index()
+                .and_then(|index| items.get_mut(&index));
+            if let (Some(table_items), Some(item_id)) = (table_items, row.try_itemid()) {
+                table_items.push((item_id, row.try_mission_drop().unwrap_or(false)));
+            }
+        }
+
+        let mut drops = Vec::new();
+        for entry in entries {
+            let loot_table_index = match entry.try_loot_table_index() {
+                Some(index) => index,
+                None => continue,
+            };
+            let (rarity_table_index, percent, min_to_drop, max_to_drop) = match (
+                entry.try_rarity_table_index(),
+                entry.try_percent(),
+                entry.try_min_to_drop(),
+                entry.try_max_to_drop(),
+            ) {
+                (Some(rarity), Some(percent), Some(min), Some(max)) => (rarity, percent, min, max),
+                _ => continue,
+            };
+            for &(item_id, mission_drop) in &items[&loot_table_index] {
+                drops.push(LootDrop {
+                    item_id,
+                    loot_table_index,
+                    rarity_table_index,
+                    percent,
+                    min_to_drop,
+                    max_to_drop,
+                    mission_drop,
+                });
+            }
+        }
+        drops
+    }
+
+    /// Get the indices of all loot tables that contain the specified item
+    ///
+    /// `LootTable` is keyed by the LOT of the item, so this only needs to check one
+    /// bucket and does not scan the table. The result is sorted and has no duplicates.
+    pub fn loot_tables_containing(&self, item_lot: i32) -> Vec<i32> {
+        let indices: BTreeSet<i32> = self
+            .loot_table
+            .key_iter(item_lot)
+            .filter_map(|row| row.try_loot_table_index())
+            .collect();
+        indices.into_iter().collect()
+    }
+
+    /// Get all missions of a `defined_type`, in the order they appear in the UI
+    ///
+    /// Missions are sorted by `UISortOrder`, with the ones that don't have one last.
+    pub fn missions_sorted_for_ui(&self, defined_type: &str) -> Vec<MissionsRow<'a, '_>> {
+        let mut missions: Vec<_> = self
+            .missions
+            .row_iter()
+            .filter(|row| {
+                row.try_defined_type()
+                    .is_some_and(|ty| ty.decode() == defined_type)
+            })
+            .collect();
+        missions.sort_by_key(|row| {
+            let order = row.ui_sort_order();
+            (order.is_none(), order)
+        });
+        missions
+    }
+
+    /// Get the skill behaviors of all skills of the specified LOT
+    ///
+    /// Each skill is only included once, ordered by skill ID.
+    pub fn get_object_skill_behaviors(&self, lot: i32) -> Vec<SkillBehaviorRow<'a, '_>> {
+        let skill_ids: BTreeSet<i32> = self
+            .object_skills
+            .key_iter(lot)
+            .filter_map(|row| row.try_skill_id())
+            .collect();
+        skill_ids
+            .into_iter()
+            .filter_map(|skill_id| SkillBehaviorRow::get_by_id(&self.skills, skill_id, skill_id))
+            .collect()
+    }
+
+    /// Get the behavior template for the specified behavior ID
+    pub fn get_behavior_template(&self, behavior_id: i32) -> Option<BehaviorTemplateRow<'a, '_>> {
+        BehaviorTemplateRow::get_by_id(&self.behavior_templates, behavior_id, behavior_id)
+    }
+
+    /// Get the visual effect of the specified behavior
+    pub fn get_behavior_effect(&self, behavior_id: i32) -> Option<BehaviorEffect<'a>> {
+        let template = self.get_behavior_template(behavior_id)?;
+        Some(BehaviorEffect {
+            effect_id: template.try_effect_id()?,
+            effect_handle: template.effect_handle(),
+        })
+    }
+
+    /// Get all behavior templates that use the specified effect ID
+    ///
+    /// `effectID` is not indexed, so this scans the whole `BehaviorTemplate` table.
+    pub fn behavior_templates_for_effect(
+        &self,
+        effect_id: i32,
+    ) -> Vec<BehaviorTemplateRow<'a, '_>> {
+        self.behavior_templates
+            .row_iter()
+            .filter(|row| row.try_effect_id() == Some(effect_id))
+            .collect()
+    }
+
+    /// Get all LOTs whose render component uses the specified icon ID
+    ///
+    /// There is no index for this, so it scans the whole `RenderComponent` and
+    /// `ComponentsRegistry` tables once. The result is sorted and has no duplicates.
+    pub fn objects_using_icon(&self, icon_id: i32) -> Vec<i32> {
+        let render_ids: BTreeSet<i32> = self
+            .render_comp
+            .row_iter()
+            .filter(|row| row.icon_id() == Some(icon_id))
+            .filter_map(|row| row.try_id())
+            .collect();
+        if render_ids.is_empty() {
+            return Vec::new();
+        }
+        let lots: BTreeSet<i32> = self
+            .comp_reg
+            .row_iter()
+            .filter(|row| {
+                row.try_component_type().map(ComponentType::from) == Some(ComponentType::Render)
+                    && row
+                        .try_component_id()
+                        .is_some_and(|id| render_ids.contains(&id))
+            })
+            .filter_map(|row| row.try_id())
+            .collect();
+        lots.into_iter().collect()
+    }
+
+    /// Iterate over all LOTs that have a component of the specified type
+    pub fn objects_with_component(&self, component_type: i32) -> impl Iterator<Item = i32> + '_ {
+        self.comp_reg
+            .row_iter()
+            .filter(move |row| row.try_component_type() == Some(component_type))
+            .filter_map(|row| row.try_id())
+    }
+}
+
+/// Borrowing accessors for the tables
+///
+/// These return the same handles as the public fields, which is more convenient
+/// when composing with closures or trait objects.
+impl<'a> TypedDatabase<'a> {
+    /// Get the `ActivityRewards` table, if present
+    pub fn activity_rewards(&self) -> Option<&ActivityRewardsTable<'a>> {
+        self.activity_rewards.as_ref()
+    }
+
+    /// Get the `BehaviorParameter` table
+    pub fn behavior_parameters(&self) -> &BehaviorParameterTable<'a> {
+        &self.behavior_parameters
+    }
+
+    /// Get the `BehaviorTemplate` table
+    pub fn behavior_templates(&self) -> &BehaviorTemplateTable<'a> {
+        &self.behavior_templates
+    }
+
+    /// Get the `ComponentsRegistry` table
+    pub fn comp_reg(&self) -> &ComponentsRegistryTable<'a> {
+        &self.comp_reg
+    }
+
+    /// Get the `DestructibleComponent` table
+    pub fn destructible_component(&self) -> &DestructibleComponentTable<'a> {
+        &self.destructible_component
+    }
+
+    /// Get the `Emotes` table, if present
+    pub fn emotes(&self) -> Option<&EmotesTable<'a>> {
+        self.emotes.as_ref()
+    }
+
+    /// Get the `Icons` table
+    pub fn icons(&self) -> &IconsTable<'a> {
+        &self.icons
+    }
+
+    /// Get the `ItemSets` table
+    pub fn item_sets(&self) -> &ItemSetsTable<'a> {
+        &self.item_sets
+    }
+
+    /// Get the `ItemSetSkills` table
+    pub fn item_set_skills(&self) -> &ItemSetSkillsTable<'a> {
+        &self.item_set_skills
+    }
+
+    /// Get the `LootMatrix` table, if present
+    pub fn loot_matrix(&self) -> Option<&LootMatrixTable<'a>> {
+        self.loot_matrix.as_ref()
+    }
+
+    /// Get the `LootTable` table
+    pub fn loot_table(&self) -> &LootTableTable<'a> {
+        &self.loot_table
+    }
+
+    /// Get the `Missions` table
+    pub fn missions(&self) -> &MissionsTable<'a> {
+        &self.missions
+    }
+
+    /// Get the `MissionTasks` table
+    pub fn mission_tasks(&self) -> &MissionTasksTable<'a> {
+        &self.mission_tasks
+    }
+
+    /// Get the `Objects` table
+    pub fn objects(&self) -> &ObjectsTable<'a> {
+        &self.objects
+    }
+
+    /// Get the `ObjectSkills` table
+    pub fn object_skills(&self) -> &ObjectSkillsTable<'a> {
+        &self.object_skills
+    }
+
+    /// Get the `RebuildComponent` table
+    pub fn rebuild_component(&self) -> &RebuildComponentTable<'a> {
+        &self.rebuild_component
+    }
+
+    /// Get the `Release_Version` table, if present
+    pub fn release_version(&self) -> Option<&ReleaseVersionTable<'a>> {
+        self.release_version.as_ref()
+    }
+
+    /// Get the `RenderComponent` table
+    pub fn render_comp(&self) -> &RenderComponentTable<'a> {
+        &self.render_comp
+    }
+
+    /// Get the `SkillBehavior` table
+    pub fn skills(&self) -> &SkillBehaviorTable<'a> {
+        &self.skills
+    }
 }
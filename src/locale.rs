@@ -0,0 +1,29 @@
+//! # Hooks for localized strings
+
+use std::collections::HashMap;
+
+/// A source of localized strings, e.g. the `locale.xml` of the client
+pub trait Localize {
+    /// Get the localized string for a key like `Objects_1_name`
+    fn localize(&self, key: &str) -> Option<String>;
+}
+
+impl Localize for HashMap<String, String> {
+    fn localize(&self, key: &str) -> Option<String> {
+        self.get(key).cloned()
+    }
+}
+
+/// Rows that carry the `localize` and `locStatus` columns
+///
+/// These indicate whether a row has localized strings, so tools can skip the lookup
+/// for rows that don't. Both are `None` if the column is missing from the table.
+pub trait LocalizationInfo {
+    /// Whether the strings in this row are localized
+    fn is_localized(&self) -> Option<bool>;
+    /// The localization status of this row
+    ///
+    /// This is not called `loc_status`, so it doesn't clash with the accessor of the
+    /// `locStatus` column on the row types.
+    fn localization_status(&self) -> Option<i32>;
+}
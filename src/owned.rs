@@ -0,0 +1,42 @@
+//! # A database that owns its bytes
+//!
+//! A [`TypedDatabase`] borrows from the bytes of the FDB file, so the buffer has to
+//! be kept alive somewhere else. [`OwnedDatabase`] keeps both together, so it can be
+//! returned from functions that read the file, or be shared between threads.
+
+use std::slice;
+
+use crate::{LoadError, TypedDatabase};
+
+/// The bytes of an FDB file and the typed view of them
+pub struct OwnedDatabase {
+    // This borrows from `buf`, so it needs to be dropped first
+    db: TypedDatabase<'static>,
+    buf: Vec<u8>,
+}
+
+impl OwnedDatabase {
+    /// Load the bytes of an FDB file
+    ///
+    /// This fails if `buf` can't be loaded as a [`TypedDatabase`].
+    pub fn new(buf: Vec<u8>) -> Result<Self, LoadError> {
+        // SAFETY: The heap allocation of `buf` does not move when `buf` is moved into
+        // `Self`, and it is never modified or freed before `db` is dropped. `db` is
+        // only handed out with the lifetime of a borrow of `Self`, see `Self::typed`.
+        let bytes: &'static [u8] = unsafe { slice::from_raw_parts(buf.as_ptr(), buf.len()) };
+        let db = TypedDatabase::from_bytes(bytes)?;
+        Ok(Self { db, buf })
+    }
+
+    /// Get the raw bytes of the FDB file
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Get the typed view of the database
+    ///
+    /// The view is built once when the data is loaded, so this is cheap.
+    pub fn typed(&self) -> &TypedDatabase<'_> {
+        &self.db
+    }
+}
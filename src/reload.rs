@@ -0,0 +1,69 @@
+//! # Hot-swapping the database
+//!
+//! A [`TypedDatabase`] borrows from the bytes of the FDB file, so it can't be
+//! replaced while readers hold on to it. This module keeps an [`OwnedDatabase`]
+//! behind an [`ArcSwap`] instead: readers take a [`Snapshot`] and use its view,
+//! while a reload swaps in new data for all snapshots taken afterwards.
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+
+use crate::{owned::OwnedDatabase, LoadError, TypedDatabase};
+
+/// The bytes of an FDB file that can be replaced at runtime
+pub struct ReloadableDatabase {
+    current: ArcSwap<OwnedDatabase>,
+}
+
+impl ReloadableDatabase {
+    /// Create a new reloadable database
+    ///
+    /// This fails if `buf` can't be loaded as a [`TypedDatabase`].
+    pub fn new(buf: Vec<u8>) -> Result<Self, LoadError> {
+        Ok(Self::from_owned(OwnedDatabase::new(buf)?))
+    }
+
+    /// Create a new reloadable database from data that was already loaded
+    pub fn from_owned(db: OwnedDatabase) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(db),
+        }
+    }
+
+    /// Replace the database with freshly loaded bytes
+    ///
+    /// Snapshots that were taken before keep the old data. If `buf` can't be
+    /// loaded as a [`TypedDatabase`], the current data is kept and an error is returned.
+    pub fn reload(&self, buf: Vec<u8>) -> Result<(), LoadError> {
+        self.current.store(Arc::new(OwnedDatabase::new(buf)?));
+        Ok(())
+    }
+
+    /// Get the current data
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            inner: self.current.load_full(),
+        }
+    }
+}
+
+/// The data of a [`ReloadableDatabase`] at one point in time
+#[derive(Clone)]
+pub struct Snapshot {
+    inner: Arc<OwnedDatabase>,
+}
+
+impl Snapshot {
+    /// Get the raw bytes of the FDB file
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the typed view of this snapshot
+    ///
+    /// The view is built once when the data is loaded, so this is cheap.
+    pub fn typed(&self) -> &TypedDatabase<'_> {
+        self.inner.typed()
+    }
+}
@@ -0,0 +1,42 @@
+use assembly_fdb::mem::Database;
+use proptest::prelude::*;
+
+use super::fixture::{Cell, FdbBuilder, TableBuilder, Ty};
+use crate::{bucket_index, field_key, key_rows};
+
+/// A table where bucket `i` holds exactly one row, with the key `i`
+fn one_row_per_bucket(bucket_count: u32) -> Vec<u8> {
+    let mut table = TableBuilder::new(&[("id", Ty::Integer)]);
+    table.buckets(bucket_count);
+    for key in 0..bucket_count {
+        table.raw_row(vec![Cell::Integer(key as i32)]);
+    }
+    let mut fdb = FdbBuilder::default();
+    fdb.insert("Test", table);
+    fdb.build()
+}
+
+proptest! {
+    #[test]
+    fn bucket_index_matches_bucket_for_hash(bucket_count in 1u32..64, key in any::<i32>()) {
+        let buf = one_row_per_bucket(bucket_count);
+        let table = Database::new(&buf)
+            .tables()
+            .unwrap()
+            .by_name("Test")
+            .unwrap()
+            .unwrap();
+
+        let index = bucket_index(key as u32, bucket_count as usize);
+        let expected = table
+            .bucket_for_hash(key as u32)
+            .row_iter()
+            .next()
+            .and_then(|row| row.field_at(0))
+            .and_then(field_key);
+        prop_assert_eq!(expected, Some(index as i32));
+
+        let found = key_rows(table, key).next().and_then(|row| row.field_at(0)).and_then(field_key);
+        prop_assert_eq!(found, Some(index as i32));
+    }
+}
@@ -0,0 +1,63 @@
+use std::thread;
+
+use super::fixture::{Cell, FdbBuilder};
+use crate::{cache::ConcurrentCachingDatabase, TypedDatabase};
+
+const LOTS: i32 = 64;
+
+/// Objects `0..LOTS`, where the even ones have a render component with an icon
+fn objects_with_icons() -> Vec<u8> {
+    let mut fdb = FdbBuilder::from_spec();
+    for lot in 0..LOTS {
+        fdb.table("Objects").sample_row(&[
+            ("id", Cell::Integer(lot)),
+            ("name", Cell::Text(format!("Object {}", lot))),
+        ]);
+        if lot % 2 == 0 {
+            let render_id = 1000 + lot;
+            fdb.table("ComponentsRegistry").row(&[
+                ("id", Cell::Integer(lot)),
+                ("component_type", Cell::Integer(2)),
+                ("component_id", Cell::Integer(render_id)),
+            ]);
+            fdb.table("RenderComponent").sample_row(&[
+                ("id", Cell::Integer(render_id)),
+                ("icon_asset", Cell::Text(format!("icons/{}.dds", lot))),
+            ]);
+        }
+    }
+    fdb.build()
+}
+
+#[test]
+fn concurrent_lookups_match_the_database() {
+    let buf = objects_with_icons();
+    let cached = ConcurrentCachingDatabase::new(TypedDatabase::from_bytes(&buf).unwrap());
+    let db = cached.inner();
+
+    thread::scope(|scope| {
+        for offset in 0..8 {
+            let cached = &cached;
+            scope.spawn(move || {
+                for round in 0..4 {
+                    for i in 0..LOTS {
+                        let lot = (i + offset * 7 + round) % LOTS;
+                        assert_eq!(cached.get_components(lot), db.get_components(lot));
+                        assert_eq!(
+                            cached.get_object_name_desc(lot),
+                            db.get_object_name_desc(lot)
+                        );
+                        assert_eq!(
+                            cached.get_render_image_for_object(lot),
+                            db.get_render_image_for_object(lot)
+                        );
+                    }
+                }
+            });
+        }
+    });
+
+    let icon = cached.get_render_image_for_object(4).unwrap();
+    assert_eq!(icon.decode(), "icons/4.dds");
+    assert!(cached.get_render_image_for_object(5).is_none());
+}
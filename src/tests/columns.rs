@@ -0,0 +1,62 @@
+use super::fixture::{Cell, FdbBuilder};
+use crate::{
+    columns::{all_names, MissionsColumn},
+    TypedDatabase, TypedTable,
+};
+
+/// The well-known columns and their order are part of the API
+///
+/// Serialization and [`crate::SerializeColumns`] follow this order, so changes to
+/// `spec.json` or `build.rs` that reorder columns show up here.
+#[test]
+fn column_order_snapshot() {
+    let mut actual = String::new();
+    for (table, columns) in all_names() {
+        actual.push_str(&format!("{}: {}\n", table, columns.join(", ")));
+    }
+    assert_eq!(actual, include_str!("columns.snap"));
+}
+
+#[test]
+fn column_min_and_max_skip_null() {
+    let mut fdb = FdbBuilder::from_spec();
+    for order in &[
+        Cell::Integer(5),
+        Cell::Nothing,
+        Cell::Integer(9),
+        Cell::Integer(7),
+    ] {
+        fdb.table("Missions")
+            .row(&[("id", Cell::Integer(1)), ("UISortOrder", order.clone())]);
+    }
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    assert_eq!(db.missions.column_min(MissionsColumn::UiSortOrder), Some(5));
+    assert_eq!(db.missions.column_max(MissionsColumn::UiSortOrder), Some(9));
+    // Only `NULL` in this column
+    assert_eq!(db.missions.column_min(MissionsColumn::OfferObjectId), None);
+    assert_eq!(db.missions.column_max(MissionsColumn::OfferObjectId), None);
+}
+
+#[test]
+fn distinct_col_values_in_first_seen_order() {
+    let mut fdb = FdbBuilder::from_spec();
+    for (id, ty) in (1..).zip(&["Mission", "Achievement", "Mission", "Daily", "Achievement"]) {
+        fdb.table("Missions").row(&[
+            ("id", Cell::Integer(id)),
+            ("defined_type", Cell::Text(ty.to_string())),
+        ]);
+    }
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let values: Vec<String> = db
+        .missions
+        .distinct_col_values(MissionsColumn::DefinedType)
+        .into_iter()
+        .filter_map(|field| field.into_opt_text())
+        .map(|text| text.decode().into_owned())
+        .collect();
+    assert_eq!(values, ["Mission", "Achievement", "Daily"]);
+}
@@ -0,0 +1,108 @@
+use super::fixture::{Cell, FdbBuilder};
+use crate::{ext::ComponentType, TypedDatabase};
+
+#[test]
+fn get_components_skips_short_rows() {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.table("ComponentsRegistry")
+        .raw_row(vec![Cell::Integer(10), Cell::Integer(2)])
+        .raw_row(vec![Cell::Integer(10), Cell::Integer(2), Cell::Integer(33)])
+        .raw_row(vec![Cell::Integer(10)])
+        .raw_row(vec![Cell::Integer(11), Cell::Integer(2)]);
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    assert_eq!(db.get_components(10).render, Some(33));
+    assert_eq!(db.get_components(11).render, None);
+}
+
+#[test]
+fn objects_batch_matches_individual_lookups() {
+    let mut fdb = FdbBuilder::from_spec();
+    for &(lot, name) in &[(1, "first"), (1, "duplicate"), (2, "second"), (3, "third")] {
+        fdb.table("Objects").sample_row(&[
+            ("id", Cell::Integer(lot)),
+            ("name", Cell::Text(name.to_owned())),
+        ]);
+    }
+    for &(lot, ty, id) in &[(1, 2, 50), (1, 2, 51), (2, 7, 8), (2, 2, 60), (4, 2, 50)] {
+        fdb.table("ComponentsRegistry").row(&[
+            ("id", Cell::Integer(lot)),
+            ("component_type", Cell::Integer(ty)),
+            ("component_id", Cell::Integer(id)),
+        ]);
+    }
+    fdb.table("RenderComponent")
+        .row(&[
+            ("id", Cell::Integer(50)),
+            ("icon_asset", Cell::Text("first.dds".to_owned())),
+        ])
+        .row(&[
+            ("id", Cell::Integer(50)),
+            ("icon_asset", Cell::Text("duplicate.dds".to_owned())),
+        ])
+        .row(&[
+            ("id", Cell::Integer(51)),
+            ("icon_asset", Cell::Text("other.dds".to_owned())),
+        ])
+        .row(&[("id", Cell::Integer(60))]);
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let lots = [1, 2, 3, 4, 1];
+    let batch = db.get_objects_batch(&lots);
+    assert_eq!(batch.len(), lots.len());
+    for (&lot, object) in lots.iter().zip(&batch) {
+        assert_eq!(object.is_some(), db.object_exists(lot));
+        if let Some(object) = object {
+            let name = db
+                .objects
+                .key_iter(lot)
+                .next()
+                .and_then(|row| row.try_name());
+            assert_eq!(object.id, lot);
+            assert_eq!(object.name, name);
+            assert_eq!(object.components, db.get_components(lot));
+            assert_eq!(object.render_image, db.get_render_image_for_object(lot));
+        }
+    }
+
+    let first = batch[0].unwrap();
+    assert_eq!(
+        first.name.map(|name| name.decode().into_owned()).as_deref(),
+        Some("first")
+    );
+    assert_eq!(first.components.render, Some(50));
+    assert_eq!(
+        first
+            .render_image
+            .map(|path| path.decode().into_owned())
+            .as_deref(),
+        Some("first.dds")
+    );
+}
+
+#[test]
+fn component_types_of_an_object() {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.table("ComponentsRegistry")
+        .raw_row(vec![Cell::Integer(10), Cell::Integer(7), Cell::Integer(1)])
+        .raw_row(vec![Cell::Integer(10), Cell::Integer(2), Cell::Integer(2)])
+        .raw_row(vec![Cell::Integer(10), Cell::Integer(42), Cell::Integer(3)])
+        .raw_row(vec![Cell::Integer(10)])
+        .raw_row(vec![Cell::Integer(11), Cell::Integer(2), Cell::Integer(4)]);
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    assert_eq!(
+        db.get_component_types(10),
+        [
+            ComponentType::Destructible,
+            ComponentType::Render,
+            ComponentType::Other(42)
+        ]
+    );
+    assert!(db.get_component_types(12).is_empty());
+    assert_eq!(ComponentType::from(42).id(), 42);
+    assert_eq!(ComponentType::Render.id(), 2);
+}
@@ -0,0 +1,51 @@
+use assembly_fdb::mem::Database;
+
+use super::fixture::{Cell, FdbBuilder};
+use crate::{domains::MissionDb, TypedDatabase};
+
+/// An FDB that only has the mission tables
+fn missions_only() -> Vec<u8> {
+    let mut spec = FdbBuilder::from_spec();
+    let mut fdb = FdbBuilder::default();
+    for &name in &["Missions", "MissionTasks"] {
+        fdb.insert(name, spec.table(name).clone());
+    }
+    fdb.table("MissionTasks").sample_row(&[
+        ("id", Cell::Integer(3)),
+        ("IconID", Cell::Integer(17)),
+        ("uid", Cell::Integer(300)),
+    ]);
+    fdb.build()
+}
+
+#[test]
+fn mission_db_on_its_own() {
+    let buf = missions_only();
+    assert!(TypedDatabase::from_bytes(&buf).is_err());
+
+    let tables = Database::new(&buf).tables().unwrap();
+    let mission_db = MissionDb::new(tables).unwrap();
+    let tasks: Vec<_> = mission_db
+        .mission_tasks
+        .key_iter(3)
+        .map(|row| (row.try_icon_id(), row.try_uid()))
+        .collect();
+    assert_eq!(tasks, [(Some(17), Some(300))]);
+}
+
+#[test]
+fn views_of_the_full_database() {
+    let buf = FdbBuilder::from_spec().sample_rows().build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let catalog = db.catalog_db();
+    assert_eq!(
+        catalog.icons.row_iter().count(),
+        db.icons.row_iter().count()
+    );
+    let mission_db = db.mission_db();
+    assert_eq!(
+        mission_db.mission_tasks.row_iter().count(),
+        db.mission_tasks.row_iter().count()
+    );
+}
@@ -0,0 +1,349 @@
+//! # Synthetic FDB files
+//!
+//! This writes the FDB format directly, so the tests don't need a copy of `CDClient.fdb`.
+//! All addresses are absolute offsets into the file, and `0xFFFFFFFF` marks the end
+//! of a list.
+
+use std::collections::BTreeMap;
+
+/// The end of a linked list, or an empty bucket
+const NONE: u32 = u32::MAX;
+
+/// The type of a column, with the value used in the FDB
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Ty {
+    Nothing = 0,
+    Integer = 1,
+    Float = 3,
+    Text = 4,
+    Boolean = 5,
+    BigInt = 6,
+    VarChar = 8,
+}
+
+impl Ty {
+    fn from_spec(ty: &str) -> Self {
+        match ty {
+            "Nothing" => Self::Nothing,
+            "Integer" => Self::Integer,
+            "Float" => Self::Float,
+            "Text" => Self::Text,
+            "Boolean" => Self::Boolean,
+            "BigInt" => Self::BigInt,
+            "VarChar" => Self::VarChar,
+            _ => panic!("unknown value type {:?}", ty),
+        }
+    }
+
+    /// A value of this type, to fill the columns a test doesn't care about
+    fn sample(self) -> Cell {
+        match self {
+            Self::Nothing => Cell::Nothing,
+            Self::Integer => Cell::Integer(1),
+            Self::Float => Cell::Float(1.5),
+            Self::Text => Cell::Text("text".into()),
+            Self::Boolean => Cell::Boolean(true),
+            Self::BigInt => Cell::BigInt(1 << 40),
+            Self::VarChar => Cell::VarChar("varchar".into()),
+        }
+    }
+}
+
+/// The value of a single field
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Cell {
+    Nothing,
+    Integer(i32),
+    Float(f32),
+    Text(String),
+    Boolean(bool),
+    BigInt(i64),
+    VarChar(String),
+}
+
+impl Cell {
+    fn ty(&self) -> Ty {
+        match self {
+            Self::Nothing => Ty::Nothing,
+            Self::Integer(_) => Ty::Integer,
+            Self::Float(_) => Ty::Float,
+            Self::Text(_) => Ty::Text,
+            Self::Boolean(_) => Ty::Boolean,
+            Self::BigInt(_) => Ty::BigInt,
+            Self::VarChar(_) => Ty::VarChar,
+        }
+    }
+
+    /// The hash the FDB uses to pick the bucket for a key
+    pub(crate) fn hash(&self) -> u32 {
+        match self {
+            Self::Integer(key) => *key as u32,
+            Self::Text(key) => sdbm(key.as_bytes()),
+            _ => 0,
+        }
+    }
+}
+
+fn sdbm(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0u32, |hash, &b| {
+        u32::from(b)
+            .wrapping_add(hash << 6)
+            .wrapping_add(hash << 16)
+            .wrapping_sub(hash)
+    })
+}
+
+/// A table in a synthetic FDB
+#[derive(Debug, Clone)]
+pub(crate) struct TableBuilder {
+    columns: Vec<(String, Ty)>,
+    bucket_count: u32,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl TableBuilder {
+    /// A table with the specified columns and 16 buckets
+    pub(crate) fn new(columns: &[(&str, Ty)]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .map(|&(name, ty)| (name.to_owned(), ty))
+                .collect(),
+            bucket_count: 16,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Set the number of buckets
+    pub(crate) fn buckets(&mut self, bucket_count: u32) -> &mut Self {
+        self.bucket_count = bucket_count;
+        self
+    }
+
+    /// Add a row with exactly these fields
+    ///
+    /// The row may have fewer fields than the table has columns.
+    pub(crate) fn raw_row(&mut self, fields: Vec<Cell>) -> &mut Self {
+        assert!(fields.len() <= self.columns.len(), "row is too long");
+        self.rows.push(fields);
+        self
+    }
+
+    /// Add a row, where all columns that are not in `fields` are `NULL`
+    pub(crate) fn row(&mut self, fields: &[(&str, Cell)]) -> &mut Self {
+        self.row_or(fields, |_| Cell::Nothing)
+    }
+
+    /// Add a row, where all columns that are not in `fields` have a sample value of their type
+    pub(crate) fn sample_row(&mut self, fields: &[(&str, Cell)]) -> &mut Self {
+        self.row_or(fields, Ty::sample)
+    }
+
+    fn row_or(&mut self, fields: &[(&str, Cell)], default: fn(Ty) -> Cell) -> &mut Self {
+        for (name, _) in fields {
+            assert!(
+                self.columns.iter().any(|(col, _)| col == name),
+                "unknown column {:?}",
+                name
+            );
+        }
+        let row = self
+            .columns
+            .iter()
+            .map(|(col, ty)| {
+                fields
+                    .iter()
+                    .find(|(name, _)| name == col)
+                    .map_or_else(|| default(*ty), |(_, cell)| cell.clone())
+            })
+            .collect();
+        self.rows.push(row);
+        self
+    }
+}
+
+/// A synthetic FDB file
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FdbBuilder {
+    tables: BTreeMap<String, TableBuilder>,
+}
+
+impl FdbBuilder {
+    /// An FDB with every table in `spec.json`, all of them without any rows
+    pub(crate) fn from_spec() -> Self {
+        let spec: serde_json::Value =
+            serde_json::from_str(include_str!("../../spec.json")).unwrap();
+        let mut builder = Self::default();
+        for (name, table) in spec["tables"].as_object().unwrap() {
+            let columns: Vec<(String, Ty)> = table["columns"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|col| {
+                    let name = col["name"].as_str().unwrap().to_owned();
+                    (name, Ty::from_spec(col["ty"].as_str().unwrap()))
+                })
+                .collect();
+            let table = TableBuilder {
+                columns,
+                bucket_count: 16,
+                rows: Vec::new(),
+            };
+            builder.tables.insert(name.clone(), table);
+        }
+        builder
+    }
+
+    /// Get a table, to add rows to it
+    pub(crate) fn table(&mut self, name: &str) -> &mut TableBuilder {
+        self.tables
+            .get_mut(name)
+            .unwrap_or_else(|| panic!("unknown table {:?}", name))
+    }
+
+    /// Add or replace a table
+    pub(crate) fn insert(&mut self, name: &str, table: TableBuilder) -> &mut Self {
+        self.tables.insert(name.to_owned(), table);
+        self
+    }
+
+    /// Remove a table
+    pub(crate) fn remove(&mut self, name: &str) -> &mut Self {
+        self.tables.remove(name);
+        self
+    }
+
+    /// Add a row of sample values to every table
+    pub(crate) fn sample_rows(&mut self) -> &mut Self {
+        for table in self.tables.values_mut() {
+            table.sample_row(&[]);
+        }
+        self
+    }
+
+    /// Write the FDB file
+    ///
+    /// The tables are sorted by name, so that they can be found with a binary search.
+    pub(crate) fn build(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        w.u32(self.tables.len() as u32);
+        let list_slot = w.slot();
+        w.patch_here(list_slot);
+        let table_slots: Vec<(usize, usize)> =
+            self.tables.iter().map(|_| (w.slot(), w.slot())).collect();
+
+        for ((name, table), (def_slot, data_slot)) in self.tables.iter().zip(table_slots) {
+            // Table definition
+            w.patch_here(def_slot);
+            w.u32(table.columns.len() as u32);
+            let name_slot = w.slot();
+            let columns_slot = w.slot();
+            w.patch_here(columns_slot);
+            let column_name_slots: Vec<usize> = table
+                .columns
+                .iter()
+                .map(|(_, ty)| {
+                    w.u32(*ty as u32);
+                    w.slot()
+                })
+                .collect();
+            w.string(name_slot, name);
+            for ((column, _), slot) in table.columns.iter().zip(column_name_slots) {
+                w.string(slot, column);
+            }
+
+            // Table data
+            w.patch_here(data_slot);
+            w.u32(table.bucket_count);
+            let buckets_slot = w.slot();
+            w.patch_here(buckets_slot);
+            let bucket_slots: Vec<usize> = (0..table.bucket_count).map(|_| w.slot()).collect();
+            for (index, bucket_slot) in bucket_slots.into_iter().enumerate() {
+                let rows = table.rows.iter().filter(|row| {
+                    let hash = row.first().map_or(0, Cell::hash);
+                    hash % table.bucket_count == index as u32
+                });
+                let mut next_slot = bucket_slot;
+                for row in rows {
+                    // Row list entry
+                    w.patch_here(next_slot);
+                    let row_slot = w.slot();
+                    next_slot = w.slot();
+
+                    // Row header and fields
+                    w.patch_here(row_slot);
+                    w.u32(row.len() as u32);
+                    let fields_slot = w.slot();
+                    w.patch_here(fields_slot);
+                    let value_slots: Vec<Option<usize>> = row
+                        .iter()
+                        .map(|cell| {
+                            w.u32(cell.ty() as u32);
+                            match cell {
+                                Cell::Nothing => w.u32(0),
+                                Cell::Integer(v) => w.u32(*v as u32),
+                                Cell::Float(v) => w.u32(v.to_bits()),
+                                Cell::Boolean(v) => w.u32(u32::from(*v)),
+                                Cell::Text(_) | Cell::VarChar(_) | Cell::BigInt(_) => {
+                                    return Some(w.slot())
+                                }
+                            }
+                            None
+                        })
+                        .collect();
+                    for (cell, slot) in row.iter().zip(value_slots) {
+                        match (cell, slot) {
+                            (Cell::Text(s), Some(slot)) | (Cell::VarChar(s), Some(slot)) => {
+                                w.string(slot, s)
+                            }
+                            (Cell::BigInt(v), Some(slot)) => {
+                                w.patch_here(slot);
+                                w.bytes(&v.to_le_bytes());
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        w.buf
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    /// Write a placeholder address and return its position
+    fn slot(&mut self) -> usize {
+        let pos = self.buf.len();
+        self.u32(NONE);
+        pos
+    }
+
+    /// Point the address at `slot` to the current position
+    fn patch_here(&mut self, slot: usize) {
+        let addr = self.buf.len() as u32;
+        self.buf[slot..slot + 4].copy_from_slice(&addr.to_le_bytes());
+    }
+
+    /// Write a NUL-terminated string and point the address at `slot` to it
+    fn string(&mut self, slot: usize, s: &str) {
+        self.patch_here(slot);
+        self.bytes(s.as_bytes());
+        self.buf.push(0);
+        while self.buf.len() % 4 != 0 {
+            self.buf.push(0);
+        }
+    }
+}
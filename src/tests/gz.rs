@@ -0,0 +1,47 @@
+use std::{env, fs, io::Write, path::PathBuf};
+
+use flate2::{write::GzEncoder, Compression};
+
+use super::fixture::FdbBuilder;
+use crate::gz::{load_from_gz_path, GzLoadError};
+
+/// Write `data` to a file in the temporary directory
+fn temp_file(name: &str, data: &[u8]) -> PathBuf {
+    let path = env::temp_dir().join(format!("paradox-typed-db-{}-{}", std::process::id(), name));
+    fs::write(&path, data).unwrap();
+    path
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn load_gzipped_fdb() {
+    let fdb = FdbBuilder::from_spec().build();
+    let path = temp_file("valid.fdb.gz", &gzip(&fdb));
+    let db = load_from_gz_path(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(db.as_bytes(), &fdb[..]);
+    assert!(db.typed().contains_table("Icons"));
+}
+
+#[test]
+fn load_errors() {
+    let fdb = FdbBuilder::from_spec().build();
+    let path = temp_file("plain.fdb", &fdb);
+    let result = load_from_gz_path(&path);
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(result, Err(GzLoadError::NotGzip(p)) if p == path));
+
+    let path = temp_file("invalid.fdb.gz", &gzip(b"not an fdb"));
+    let result = load_from_gz_path(&path);
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(result, Err(GzLoadError::Load(_))));
+
+    let result = load_from_gz_path(env::temp_dir().join("paradox-typed-db-missing.fdb.gz"));
+    assert!(matches!(result, Err(GzLoadError::Io(_))));
+}
@@ -0,0 +1,27 @@
+use super::fixture::{Cell, FdbBuilder};
+use crate::TypedDatabase;
+
+#[test]
+fn clone_row_iter_mid_iteration() {
+    let mut fdb = FdbBuilder::from_spec();
+    for id in 0..5 {
+        fdb.table("Icons")
+            .sample_row(&[("IconID", Cell::Integer(id))]);
+    }
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let mut iter = db.icons.row_iter();
+    let first: Vec<i32> = iter.by_ref().take(2).map(|row| row.icon_id()).collect();
+    let copy = iter.clone();
+
+    let rest: Vec<i32> = iter.map(|row| row.icon_id()).collect();
+    let rest_of_copy: Vec<i32> = copy.map(|row| row.icon_id()).collect();
+    assert_eq!(first.len(), 2);
+    assert_eq!(rest.len(), 3);
+    assert_eq!(rest, rest_of_copy);
+
+    let mut all: Vec<i32> = first.into_iter().chain(rest).collect();
+    all.sort_unstable();
+    assert_eq!(all, [0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,78 @@
+use super::fixture::{Cell, FdbBuilder, TableBuilder, Ty};
+use crate::{
+    field_key,
+    rows::{IconsRow, MissionTasksRow},
+    TypedDatabase, TypedRow, TypedTable,
+};
+
+/// Some variants of the database store the primary key of a table as text
+///
+/// The table has the IDs 0 to 31 in 16 buckets.
+fn text_keyed_icons() -> Vec<u8> {
+    let mut icons = TableBuilder::new(&[("IconID", Ty::Text), ("IconPath", Ty::Text)]);
+    for id in 0..32 {
+        icons.row(&[
+            ("IconID", Cell::Text(id.to_string())),
+            ("IconPath", Cell::Text(format!("icons/{}.dds", id))),
+        ]);
+    }
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.insert("Icons", icons);
+    fdb.build()
+}
+
+#[test]
+fn text_primary_keys() {
+    let buf = text_keyed_icons();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let path = db.get_icon_path(12).map(|p| p.decode().into_owned());
+    assert_eq!(path.as_deref(), Some("icons/12.dds"));
+    assert!(db.get_icon_path(32).is_none());
+
+    let paths: Vec<String> = db
+        .icons
+        .key_iter(7)
+        .map(|row| row.icon_path().decode().into_owned())
+        .collect();
+    assert_eq!(paths, ["icons/7.dds"]);
+
+    let row = IconsRow::get_by_id(&db.icons, 12, 12).unwrap();
+    assert_eq!(row.icon_id(), 12);
+    let row = IconsRow::get_by_key(&db.icons, 12).unwrap();
+    assert_eq!(row.icon_id(), 12);
+
+    let batch = db.get_icon_paths(&[7, 12, 32]);
+    assert_eq!(batch.len(), 2);
+
+    // Only the bucket of the hashed key is visited, not the whole table
+    let bucket = |id: i32| Cell::Text(id.to_string()).hash() % 16;
+    let keys: Vec<i32> = db
+        .icons
+        .bucket_rows(7)
+        .filter_map(|row| row.field_at(0).and_then(field_key))
+        .collect();
+    let expected: Vec<i32> = (0..32).filter(|&id| bucket(id) == bucket(7)).collect();
+    assert!(keys.contains(&7));
+    assert!(keys.len() < 32);
+    assert_eq!(keys.len(), expected.len());
+    assert!(keys.iter().all(|&id| bucket(id) == bucket(7)));
+}
+
+#[test]
+fn get_by_key_uses_the_unique_id_column() {
+    let mut fdb = FdbBuilder::from_spec();
+    for &(id, uid) in &[(3, 300), (3, 301), (4, 400)] {
+        fdb.table("MissionTasks")
+            .sample_row(&[("id", Cell::Integer(id)), ("uid", Cell::Integer(uid))]);
+    }
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let task = MissionTasksRow::get_by_key(&db.mission_tasks, 301).unwrap();
+    assert_eq!(task.try_id(), Some(3));
+    assert_eq!(task.try_uid(), Some(301));
+    let task = MissionTasksRow::get_by_key(&db.mission_tasks, 400).unwrap();
+    assert_eq!(task.try_id(), Some(4));
+    assert!(MissionTasksRow::get_by_key(&db.mission_tasks, 3).is_none());
+}
@@ -0,0 +1,54 @@
+use super::fixture::{Cell, FdbBuilder};
+use crate::{ext::LootDrop, TypedDatabase};
+
+fn rebuild_with_rewards() -> Vec<u8> {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.table("RebuildComponent")
+        .sample_row(&[("id", Cell::Integer(5)), ("activityID", Cell::Integer(9))]);
+    fdb.table("ActivityRewards").sample_row(&[
+        ("objectTemplate", Cell::Integer(9)),
+        ("activityRating", Cell::Integer(1)),
+        ("LootMatrixIndex", Cell::Integer(3)),
+        ("CurrencyIndex", Cell::Nothing),
+        ("ChallengeRating", Cell::Integer(0)),
+    ]);
+    fdb.table("LootMatrix").sample_row(&[
+        ("LootMatrixIndex", Cell::Integer(3)),
+        ("LootTableIndex", Cell::Integer(20)),
+        ("RarityTableIndex", Cell::Integer(1)),
+        ("percent", Cell::Float(0.5)),
+        ("minToDrop", Cell::Integer(1)),
+        ("maxToDrop", Cell::Integer(2)),
+    ]);
+    fdb.table("LootTable").sample_row(&[
+        ("itemid", Cell::Integer(1000)),
+        ("LootTableIndex", Cell::Integer(20)),
+        ("MissionDrop", Cell::Boolean(false)),
+    ]);
+    fdb.build()
+}
+
+#[test]
+fn rebuild_rewards_resolve_items() {
+    let buf = rebuild_with_rewards();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    let rewards = db.get_rebuild_rewards(5);
+    assert_eq!(rewards.len(), 1);
+    let reward = &rewards[0];
+    assert_eq!(reward.activity_rating, 1);
+    assert_eq!(reward.loot_matrix_index, Some(3));
+    assert_eq!(reward.currency_index, None);
+    assert_eq!(
+        reward.drops,
+        [LootDrop {
+            item_id: 1000,
+            loot_table_index: 20,
+            rarity_table_index: 1,
+            percent: 0.5,
+            min_to_drop: 1,
+            max_to_drop: 2,
+            mission_drop: false,
+        }]
+    );
+}
@@ -0,0 +1,18 @@
+use super::fixture::{Cell, FdbBuilder};
+use crate::TypedDatabase;
+
+#[test]
+fn mission_prerequisites_ignore_operators_and_suffixes() {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.table("Missions").row(&[
+        ("id", Cell::Integer(10)),
+        ("prereqMissionID", Cell::Text("(1|2),3:2".to_owned())),
+    ]);
+    fdb.table("Missions").row(&[("id", Cell::Integer(11))]);
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+
+    assert_eq!(db.get_mission_prerequisites(10), [1, 2, 3]);
+    assert!(db.get_mission_prerequisites(11).is_empty());
+    assert!(db.get_mission_prerequisites(12).is_empty());
+}
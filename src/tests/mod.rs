@@ -0,0 +1,22 @@
+mod buckets;
+#[cfg(feature = "dashmap")]
+mod cache;
+mod columns;
+mod components;
+#[cfg(feature = "domains")]
+mod domains;
+pub(crate) mod fixture;
+#[cfg(feature = "flate2")]
+mod gz;
+mod iter;
+mod keys;
+mod loot;
+mod missions;
+mod names;
+mod optional;
+#[cfg(feature = "arc-swap")]
+mod reload;
+#[cfg(feature = "schemars")]
+mod schema;
+mod serialize;
+mod util;
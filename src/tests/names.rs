@@ -0,0 +1,42 @@
+use assembly_fdb::{common::Value, mem::Database};
+
+use super::fixture::{Cell, FdbBuilder};
+use crate::{LoadError, TableNameMap, TypedDatabase};
+
+fn renamed_missions() -> Vec<u8> {
+    let mut fdb = FdbBuilder::from_spec();
+    let mut missions = fdb.table("Missions").clone();
+    missions.sample_row(&[("id", Cell::Integer(42))]);
+    fdb.remove("Missions");
+    fdb.insert("cdclient_Missions", missions);
+    fdb.build()
+}
+
+#[test]
+fn missing_table_reports_the_searched_name() {
+    let buf = FdbBuilder::from_spec().build();
+    let tables = Database::new(&buf).tables().unwrap();
+    let mut names = TableNameMap::new();
+    names.insert("Missions", "cdclient_Missions".to_owned());
+    match TypedDatabase::new_with_names(tables, &names) {
+        Err(LoadError::MissingTable(name)) => assert_eq!(name, "cdclient_Missions"),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("loaded a database without cdclient_Missions"),
+    }
+}
+
+#[test]
+fn renamed_tables_by_name() {
+    let buf = renamed_missions();
+    let tables = Database::new(&buf).tables().unwrap();
+    let mut names = TableNameMap::new();
+    names.insert("Missions", "cdclient_Missions".to_owned());
+    let db = TypedDatabase::new_with_names(tables, &names).unwrap();
+
+    assert!(db.contains_table("Missions"));
+    assert_eq!(db.get_field("Missions", 42, "id"), Some(Value::Integer(42)));
+    assert_eq!(
+        db.visit_table("Missions", |table| table.row_iter().count()),
+        Some(1)
+    );
+}
@@ -0,0 +1,32 @@
+use super::fixture::FdbBuilder;
+use crate::TypedDatabase;
+
+fn without(name: &str) -> Vec<u8> {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.remove(name);
+    fdb.build()
+}
+
+#[test]
+fn without_emotes() {
+    let buf = without("Emotes");
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+    assert!(db.emotes().is_none());
+    assert!(db.get_emote_display(1).is_none());
+}
+
+#[test]
+fn without_loot_matrix() {
+    let buf = without("LootMatrix");
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+    assert!(db.loot_matrix().is_none());
+    assert!(db.get_object_drops(1).is_empty());
+}
+
+#[test]
+fn without_activity_rewards() {
+    let buf = without("ActivityRewards");
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+    assert!(db.activity_rewards().is_none());
+    assert!(db.get_rebuild_rewards(1).is_empty());
+}
@@ -0,0 +1,35 @@
+use super::fixture::{Cell, FdbBuilder};
+use crate::reload::ReloadableDatabase;
+
+fn with_icon(path: &str) -> Vec<u8> {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.table("Icons").sample_row(&[
+        ("IconID", Cell::Integer(1)),
+        ("IconPath", Cell::Text(path.to_owned())),
+    ]);
+    fdb.build()
+}
+
+#[test]
+fn reload_while_a_reader_holds_the_view() {
+    let reloadable = ReloadableDatabase::new(with_icon("old.dds")).unwrap();
+    let snapshot = reloadable.snapshot();
+    let db = snapshot.typed();
+    assert_eq!(db.get_icon_path(1).unwrap().decode(), "old.dds");
+
+    reloadable.reload(with_icon("new.dds")).unwrap();
+    assert!(reloadable.reload(vec![1, 2, 3]).is_err());
+    let current = reloadable.snapshot();
+    assert_eq!(
+        current.typed().get_icon_path(1).unwrap().decode(),
+        "new.dds"
+    );
+
+    // The old view stays valid, even after the reloadable database is gone
+    drop(reloadable);
+    assert_eq!(db.get_icon_path(1).unwrap().decode(), "old.dds");
+    assert_eq!(
+        current.typed().get_icon_path(1).unwrap().decode(),
+        "new.dds"
+    );
+}
@@ -0,0 +1,15 @@
+use crate::{columns::all_names, rows::schema_names};
+
+#[test]
+fn schema_properties_are_the_column_names() {
+    let columns = all_names();
+    let schemas = schema_names();
+    assert_eq!(columns.len(), schemas.len());
+    for ((table, columns), (schema_table, mut properties)) in columns.into_iter().zip(schemas) {
+        assert_eq!(table, schema_table);
+        let mut columns: Vec<String> = columns.into_iter().map(String::from).collect();
+        columns.sort();
+        properties.sort();
+        assert_eq!(columns, properties, "properties of {}", table);
+    }
+}
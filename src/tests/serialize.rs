@@ -0,0 +1,57 @@
+use assembly_fdb::mem::Database;
+
+use super::fixture::{Cell, FdbBuilder, TableBuilder, Ty};
+use crate::{
+    columns::{all_names, IconsColumn},
+    rows::serialize_all,
+    SerializeColumns, TypedDatabase,
+};
+
+/// An `Icons` table where `IconPath` has the wrong type
+fn icons_with_integer_paths() -> Vec<u8> {
+    let mut icons = TableBuilder::new(&[("IconID", Ty::Integer), ("IconPath", Ty::Integer)]);
+    icons.row(&[("IconID", Cell::Integer(1)), ("IconPath", Cell::Integer(2))]);
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.insert("Icons", icons);
+    fdb.build()
+}
+
+#[test]
+fn wrong_column_type_is_an_error() {
+    let buf = icons_with_integer_paths();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+    let row = db.icons.row_iter().next().unwrap();
+
+    let err = serde_json::to_string(&row).unwrap_err();
+    assert!(err.to_string().contains("IconPath"), "{}", err);
+
+    let columns = [IconsColumn::IconPath];
+    assert!(serde_json::to_string(&row.select(&columns)).is_err());
+
+    let columns = [IconsColumn::IconId];
+    let json = serde_json::to_string(&row.select(&columns)).unwrap();
+    assert_eq!(json, r#"{"IconID":1}"#);
+}
+
+#[test]
+fn serialize_every_row_type() {
+    let buf = FdbBuilder::from_spec().sample_rows().build();
+    let tables = Database::new(&buf).tables().unwrap();
+
+    let rows = serialize_all(tables);
+    assert_eq!(rows.len(), all_names().len());
+    for ((table, row), (name, columns)) in rows.into_iter().zip(all_names()) {
+        assert_eq!(table, name);
+        match row {
+            Ok(serde_json::Value::Object(fields)) => {
+                let mut keys: Vec<&str> = fields.keys().map(String::as_str).collect();
+                let mut expected = columns;
+                keys.sort_unstable();
+                expected.sort_unstable();
+                assert_eq!(keys, expected, "{}", table);
+            }
+            Ok(other) => panic!("{} serialized as {}", table, other),
+            Err(e) => panic!("{}: {}", table, e),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use assembly_fdb::common::Value;
+#[cfg(feature = "indexmap")]
+use assembly_fdb::core::Field as OwnedValue;
+
+#[cfg(feature = "indexmap")]
+use super::fixture::{Cell, FdbBuilder};
+use crate::util::format_cell;
+#[cfg(feature = "indexmap")]
+use crate::{util::row_as_map, TypedDatabase, TypedTable};
+
+#[test]
+fn format_cell_for_every_type() {
+    assert_eq!(format_cell(Value::Nothing), "");
+    assert_eq!(format_cell(Value::Integer(-12)), "-12");
+    assert_eq!(format_cell(Value::Float(2.0)), "2");
+    assert_eq!(format_cell(Value::Float(0.25)), "0.25");
+    assert_eq!(format_cell(Value::Boolean(true)), "true");
+    assert_eq!(format_cell(Value::BigInt(1 << 40)), "1099511627776");
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn row_as_map_keeps_the_column_order() {
+    let mut fdb = FdbBuilder::from_spec();
+    fdb.table("Icons").row(&[
+        ("IconID", Cell::Integer(3)),
+        ("IconPath", Cell::Text("icons/3.dds".into())),
+    ]);
+    let buf = fdb.build();
+    let db = TypedDatabase::from_bytes(&buf).unwrap();
+    let table = db.icons.as_raw();
+    let row = table.row_iter().next().unwrap();
+
+    let map = row_as_map(&table, row);
+    let keys: Vec<&str> = map.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["IconID", "IconPath", "IconName"]);
+    assert_eq!(map["IconID"], OwnedValue::Integer(3));
+    assert_eq!(map["IconName"], OwnedValue::Nothing);
+}
@@ -0,0 +1,54 @@
+//! # Utilities for working with raw FDB data
+
+#[cfg(feature = "indexmap")]
+use assembly_fdb::mem::{Row, Table};
+use assembly_fdb::{common::Value, core::Field as OwnedValue, mem::Field};
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+
+/// Render a field as a display string
+///
+/// - Integers are printed as-is
+/// - Floats are printed without trailing zeros
+/// - Text is decoded from Latin-1
+/// - Booleans are printed as `true` / `false`
+/// - `NULL` is printed as the empty string
+pub fn format_cell(value: Field) -> String {
+    match value {
+        Value::Nothing => String::new(),
+        Value::Integer(v) => v.to_string(),
+        Value::Float(v) => v.to_string(),
+        Value::Text(v) => v.decode().into_owned(),
+        Value::Boolean(v) => v.to_string(),
+        Value::BigInt(v) => v.to_string(),
+        Value::VarChar(v) => v.decode().into_owned(),
+    }
+}
+
+/// Convert a field to an owned value
+///
+/// Text is decoded from Latin-1.
+pub fn to_owned_value(value: Field) -> OwnedValue {
+    match value {
+        Value::Nothing => OwnedValue::Nothing,
+        Value::Integer(v) => OwnedValue::Integer(v),
+        Value::Float(v) => OwnedValue::Float(v),
+        Value::Text(v) => OwnedValue::Text(v.decode().into_owned()),
+        Value::Boolean(v) => OwnedValue::Boolean(v),
+        Value::BigInt(v) => OwnedValue::BigInt(v),
+        Value::VarChar(v) => OwnedValue::VarChar(v.decode().into_owned()),
+    }
+}
+
+/// Convert a raw row to a map from column name to value
+///
+/// The entries are in the order of the columns in `table`. This requires the
+/// `indexmap` feature.
+#[cfg(feature = "indexmap")]
+pub fn row_as_map(table: &Table, row: Row) -> IndexMap<String, OwnedValue> {
+    table
+        .column_iter()
+        .zip(row.field_iter())
+        .map(|(col, field)| (col.name_raw().decode().into_owned(), to_owned_value(field)))
+        .collect()
+}